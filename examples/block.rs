@@ -0,0 +1,30 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Blocks")
+            .align(Alignment::Center)
+            .block(Block::bordered().border_type(BorderType::Double)),
+        hbox(widgets![
+            lable("plain").wrap(),
+            lable("rounded").block(Block::bordered().border_type(BorderType::Rounded)),
+            lable("thick").block(Block::bordered().border_type(BorderType::Thick)),
+            lable("ascii").block(Block::bordered().border_type(BorderType::Ascii))
+        ]),
+        lable("Only top and bottom borders, 2 columns of padding").block(
+            Block::new()
+                .borders(Borders::TOP | Borders::BOTTOM)
+                .padding(Padding::horizontal(2))
+        ),
+        hbox(widgets![button("OK"), button("Cancel")]).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(Span::styled("Actions", Style::new().bold()))
+                .title_align(Alignment::Center)
+        ),
+        block(text_input().no_wrap().placeholder("Search..."))
+            .title("Search")
+            .border_style(Style::new().fg(Color::Cyan))
+    ])
+    .run()
+}
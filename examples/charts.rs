@@ -0,0 +1,19 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        sparkline(vec![3, 5, 2, 8, 13, 9, 4, 6, 11, 15, 7, 3, 5, 9, 12, 10])
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::bordered().title("CPU")),
+        bar_chart(vec![
+            ("Mon", 12),
+            ("Tue", 30),
+            ("Wed", 21),
+            ("Thu", 8),
+            ("Fri", 17)
+        ])
+        .bar_style(Style::default().fg(Color::Cyan))
+        .block(Block::bordered().title("Requests"))
+    ])
+    .run()
+}
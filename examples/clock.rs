@@ -0,0 +1,47 @@
+use simple_tui::functions::*;
+use std::time::{Duration, Instant};
+
+/// Application state, changed by the timers.
+struct Clock {
+    started: Instant,
+    ticks: u64,
+    paused: bool,
+}
+
+fn main() -> std::io::Result<()> {
+    let mut root = vbox(widgets![
+        lable("").set_id("elapsed").align(Alignment::Center).wrap(),
+        progress_bar().set_id("second").label("ticks"),
+        lable("").set_id("status").align(Alignment::Center),
+        button("Pause / Resume").on_click_state(|c: &mut Clock| c.paused = !c.paused)
+    ]);
+
+    let mut store = Store::new(Clock {
+        started: Instant::now(),
+        ticks: 0,
+        paused: false,
+    })
+    .bind("elapsed", |c: &Clock, l: &mut Lable| {
+        l.text = format!("Running for {}s", c.started.elapsed().as_secs())
+    })
+    .bind("second", |c: &Clock, p: &mut ProgressBar| {
+        p.set_ratio((c.ticks % 10) as f64 / 10.0)
+    });
+
+    Runner::new()?
+        .every(Duration::from_millis(100), |ctx: &mut Context| {
+            ctx.update(|c: &mut Clock| {
+                if !c.paused {
+                    c.ticks += 1
+                }
+            })
+        })
+        .after(Duration::from_secs(3), |ctx: &mut Context| {
+            ctx.trigger("status", |w: &mut dyn Widget| {
+                if let Some(l) = w.downcast_mut::<Lable>() {
+                    l.text = "Esc to quit".into();
+                }
+            })
+        })
+        .run_with(&mut root, &mut store)
+}
@@ -0,0 +1,31 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Tab to move, Space to change, or click").set_id("status"),
+        checkbox("Send notifications").set_rid("status").on_change(
+            |w: &mut dyn Widget, checked: bool| {
+                if let Some(l) = w.downcast_mut::<Lable>() {
+                    l.text = format!("Notifications: {checked}");
+                }
+            }
+        ),
+        toggle("Dark mode")
+            .on(true)
+            .set_rid("status")
+            .on_change(|w: &mut dyn Widget, on: bool| {
+                if let Some(l) = w.downcast_mut::<Lable>() {
+                    l.text = format!("Dark mode: {}", if on { "on" } else { "off" });
+                }
+            }),
+        radio_group(vec!["Small", "Medium", "Large"])
+            .select(1)
+            .set_rid("status")
+            .on_change(|w: &mut dyn Widget, _: usize, size: &str| {
+                if let Some(l) = w.downcast_mut::<Lable>() {
+                    l.text = format!("Size: {size}");
+                }
+            })
+    ])
+    .run()
+}
@@ -1,23 +1,35 @@
 use simple_tui::functions::*;
 
-fn add_one(w: &mut Widget) -> &mut Widget {
-    if let Widget::Lable(l) = w {
-        let n = &l.text["Counter: ".len()..].parse::<usize>().unwrap();
-        l.text = "Counter: ".to_string() + &(n + 1).to_string();
-    }
-    w
+/// Application state, the Widgets only show it.
+#[derive(Default)]
+struct Counter {
+    count: i64,
+    clicks: usize,
 }
 
-fn main() {
-    vbox(widgets![
-        lable("Counter: 0")
-            .set_id(1337)
-            .align(Alignment::Center)
-            .wrap(),
-            
-        button("Click me!")
-            .set_rid(1337)
-            .on_click(|w| add_one(w))
-    ])
-    .start();
+fn main() -> std::io::Result<()> {
+    let mut root = vbox(widgets![
+        lable("").set_id(1).align(Alignment::Center).wrap(),
+        lable("").set_id(2).align(Alignment::Center),
+        hbox(widgets![
+            button("-1").on_click_state(|c: &mut Counter| {
+                c.count -= 1;
+                c.clicks += 1;
+            }),
+            button("+1").on_click_state(|c: &mut Counter| {
+                c.count += 1;
+                c.clicks += 1;
+            }),
+            button("Reset").on_click_state(|c: &mut Counter| *c = Counter::default())
+        ])
+    ]);
+
+    Store::new(Counter::default())
+        .bind(1, |c: &Counter, l: &mut Lable| {
+            l.text = format!("Counter: {}", c.count)
+        })
+        .bind(2, |c: &Counter, l: &mut Lable| {
+            l.text = format!("{} clicks", c.clicks)
+        })
+        .run(&mut root)
 }
@@ -0,0 +1,27 @@
+use simple_tui::functions::*;
+
+fn confirm(w: &mut dyn Widget) {
+    if let Some(root) = w.downcast_mut::<Overlay>() {
+        root.open(
+            dialog("Delete", "Delete all the logs? This can't be undone.")
+                .buttons(vec!["Delete", "Keep"])
+                .select(1)
+                .set_rid(1)
+                .on_close(|w: &mut dyn Widget, _: usize, button: &str| {
+                    if let Some(l) = w.downcast_mut::<Lable>() {
+                        l.text = format!("You chose: {button}");
+                    }
+                }),
+        );
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    overlay(vbox(widgets![
+        lable("Nothing chosen yet").set_id(1).wrap(),
+        button("Delete logs...").set_rid(0).on_click(confirm)
+    ]))
+    .set_id(0)
+    .backdrop_style(Style::default().dim())
+    .run()
+}
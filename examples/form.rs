@@ -0,0 +1,29 @@
+use simple_tui::functions::*;
+
+fn clear(w: &mut dyn Widget) {
+    if let Some(input) = w.downcast_mut::<TextInput>() {
+        input.set_text("");
+    }
+    if let Some(cb) = w.downcast_mut::<Checkbox>() {
+        cb.set_checked(false);
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        text_input().placeholder("Name").set_id(1),
+        text_input().placeholder("Email").set_id(2),
+        checkbox("Subscribe").set_id(3),
+        hbox(widgets![
+            // one callback, three targets.
+            button("Reset").set_rids(vec![1, 2, 3]).on_click(clear),
+            // no target, the callback gets the button itself.
+            button("Send").on_click(|w: &mut dyn Widget| {
+                if let Some(b) = w.downcast_mut::<Button>() {
+                    b.text = "Sent!".into();
+                }
+            })
+        ])
+    ])
+    .run()
+}
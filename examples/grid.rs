@@ -1,6 +1,6 @@
 use simple_tui::functions::*;
 
-fn main() {
+fn main() -> std::io::Result<()> {
     vbox(widgets![
         hbox(widgets![
             lable("Hello 1").align(Alignment::Right).wrap(),
@@ -18,5 +18,5 @@ fn main() {
             lable("Hello 9").align(Alignment::Left).wrap()
         ])
     ])
-    .start();
+    .run()
 }
@@ -0,0 +1,22 @@
+use simple_tui::functions::*;
+
+fn greet(w: &mut dyn Widget, name: &str) {
+    if let Some(l) = w.downcast_mut::<Lable>() {
+        l.text = "Hello ".to_string() + name + "!";
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Type your name, then press Enter")
+            .set_id(1337)
+            .align(Alignment::Center)
+            .wrap(),
+        text_input()
+            .placeholder("Your name")
+            .max_len(32)
+            .set_rid(1337)
+            .on_submit(greet)
+    ])
+    .run()
+}
@@ -0,0 +1,24 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Header").align(Alignment::Center).wrap(),
+        hbox(widgets![
+            lable("Sidebar\n\n- Home\n- Settings")
+                .v_align(VAlignment::Top)
+                .wrap(),
+            lable("Body: the text wraps between words when the window is too narrow.")
+                .text_wrap(TextWrap::Word)
+                .align(Alignment::Center)
+                .wrap()
+        ])
+        .constraints(vec![Constraint::Percentage(25), Constraint::Fill(1)]),
+        lable("Status: ready (Esc to quit)")
+    ])
+    .constraints(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .run()
+}
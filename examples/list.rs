@@ -0,0 +1,31 @@
+use simple_tui::functions::*;
+
+fn show(w: &mut dyn Widget, _: usize, item: &str) {
+    if let Some(l) = w.downcast_mut::<Lable>() {
+        l.text = format!("Selected: {item}");
+    }
+}
+
+fn open(w: &mut dyn Widget, _: usize, item: &str) {
+    if let Some(l) = w.downcast_mut::<Lable>() {
+        l.text = format!("Opened: {item}");
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let items: Vec<String> = (1..=40).map(|i| format!("File {i}.txt")).collect();
+
+    vbox(widgets![
+        lable("Tab to focus, Up/Down to select, Enter to open")
+            .set_id(1)
+            .align(Alignment::Center)
+            .wrap(),
+        List::new(items)
+            .block(Block::bordered().title("Files"))
+            .set_rid(1)
+            .on_select(show)
+            .on_activate(open)
+    ])
+    .constraints(vec![Constraint::Length(3), Constraint::Fill(1)])
+    .run()
+}
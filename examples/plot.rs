@@ -0,0 +1,29 @@
+use simple_tui::functions::*;
+
+fn wave(f: fn(f64) -> f64) -> Vec<(f64, f64)> {
+    (0..=100)
+        .map(|i| i as f64 / 10.0)
+        .map(|x| (x, f(x)))
+        .collect()
+}
+
+fn main() -> std::io::Result<()> {
+    hbox(widgets![
+        canvas()
+            .x_bounds(-10.0, 10.0)
+            .y_bounds(-10.0, 10.0)
+            .rectangle((-9.0, -9.0), 18.0, 18.0, Color::Blue)
+            .circle((0.0, 0.0), 6.0, Color::Red)
+            .line((-9.0, -9.0), (9.0, 9.0), Color::Green)
+            .points(vec![(0.0, 0.0)], Color::Yellow)
+            .block(Block::bordered().title("Shapes")),
+        line_chart(vec![
+            Dataset::new("sin", wave(f64::sin)).color(Color::Cyan),
+            Dataset::new("cos", wave(f64::cos)).color(Color::Magenta)
+        ])
+        .x_title("t")
+        .y_title("value")
+        .block(Block::bordered().title("Waves"))
+    ])
+    .run()
+}
@@ -0,0 +1,30 @@
+use simple_tui::functions::*;
+
+fn step(delta: f64) -> impl Fn(&mut dyn Widget) {
+    move |w: &mut dyn Widget| {
+        if let Some(g) = w.downcast_mut::<Gauge>() {
+            g.set_ratio(g.get_ratio() + delta);
+        }
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Downloads").align(Alignment::Center),
+        progress_bar()
+            .ratio(0.37)
+            .show_percent()
+            .filled_style(Style::default().fg(Color::Cyan))
+            .block(Block::bordered().title("file.tar.gz")),
+        gauge()
+            .set_id(1)
+            .ratio(0.5)
+            .filled_style(Style::default().fg(Color::Green))
+            .block(Block::bordered().title("Disk")),
+        hbox(widgets![
+            button("-10%").set_rid(1).on_click(step(-0.1)),
+            button("+10%").set_rid(1).on_click(step(0.1))
+        ])
+    ])
+    .run()
+}
@@ -0,0 +1,22 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    let rows: Vec<Box<dyn Widget>> = (1..=30)
+        .map(|i| {
+            hbox(widgets![
+                Lable::new(format!("Item {i}")).wrap(),
+                button("Open").set_rid(0).on_click(|_| {})
+            ])
+            .constraints(vec![Constraint::Fill(1), Constraint::Length(10)])
+            .as_widget()
+        })
+        .collect();
+
+    vbox(widgets![
+        lable("Wheel, arrows, PageUp/PageDown or Tab to scroll (Esc to quit)")
+            .align(Alignment::Center),
+        scroll_view(vbox(rows))
+    ])
+    .constraints(vec![Constraint::Length(1), Constraint::Fill(1)])
+    .run()
+}
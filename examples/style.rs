@@ -0,0 +1,30 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    let ok = Style::new().fg(Color::Green).bold();
+    let failed = Style::new().fg(Color::Red).bold();
+
+    vbox(widgets![
+        lable("Dashboard")
+            .style(Style::new().fg(Color::Black).bg(Color::Cyan))
+            .align(Alignment::Center),
+        lable("").wrap().spans(vec![
+            Span::raw("api: "),
+            Span::styled("up", ok),
+            Span::raw("  db: "),
+            Span::styled("down", failed),
+        ]),
+        lable("")
+            .wrap()
+            .border_style(Style::new().fg(Color::DarkGrey))
+            .spans(vec![
+                Span::styled("bold", Style::new().bold()),
+                Span::raw(" "),
+                Span::styled("underlined", Style::new().underlined()),
+                Span::raw(" "),
+                Span::styled("reversed", Style::new().reversed()),
+            ]),
+        button("Retry").border_style(Style::new().fg(Color::Yellow))
+    ])
+    .run()
+}
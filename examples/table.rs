@@ -0,0 +1,52 @@
+use simple_tui::functions::*;
+
+fn show(w: &mut dyn Widget, _: usize, row: &[String]) {
+    if let Some(l) = w.downcast_mut::<Lable>() {
+        l.text = format!("{} uses {}% CPU", row[0], row[2]);
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let processes = [
+        ("nginx", "1021", "2.5", "running"),
+        ("postgres", "877", "11.0", "running"),
+        ("redis", "912", "0.7", "sleeping"),
+        ("cron", "301", "0.0", "sleeping"),
+        ("backup", "4410", "35.2", "running"),
+    ];
+    let rows = processes
+        .iter()
+        .map(|(name, pid, cpu, state)| {
+            vec![
+                name.to_string(),
+                pid.to_string(),
+                cpu.to_string(),
+                state.to_string(),
+            ]
+        })
+        .collect();
+
+    vbox(widgets![
+        lable("Click a header to sort, Tab then Up/Down to select").set_id(1),
+        table(vec!["Name", "PID", "CPU %", "State"])
+            .rows(rows)
+            .widths(vec![
+                Constraint::Fill(1),
+                Constraint::Length(6),
+                Constraint::Length(7),
+                Constraint::Length(10),
+            ])
+            .alignments(vec![
+                Alignment::Left,
+                Alignment::Right,
+                Alignment::Right,
+                Alignment::Center
+            ])
+            .sortable()
+            .block(Block::bordered().title("Processes"))
+            .set_rid(1)
+            .on_select(show)
+    ])
+    .constraints(vec![Constraint::Length(1), Constraint::Fill(1)])
+    .run()
+}
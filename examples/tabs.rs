@@ -0,0 +1,33 @@
+use simple_tui::functions::*;
+
+fn main() -> std::io::Result<()> {
+    vbox(widgets![
+        lable("Click a title, or Tab to the tab bar and use Left/Right").set_id(1),
+        tabs()
+            .tab(
+                "Home",
+                vbox(widgets![
+                    lable("Welcome!").align(Alignment::Center),
+                    button("Say hi").set_rid(1).on_click(|w: &mut dyn Widget| {
+                        if let Some(l) = w.downcast_mut::<Lable>() {
+                            l.text = "Hi!".into();
+                        }
+                    })
+                ])
+            )
+            .tab("Tasks", list(vec!["Write docs", "Fix bugs", "Release"]))
+            .tab(
+                "Settings",
+                vbox(widgets![checkbox("Autosave"), toggle("Dark mode")])
+            )
+            .set_rid(1)
+            .on_change(|w: &mut dyn Widget, _: usize, title: &str| {
+                if let Some(l) = w.downcast_mut::<Lable>() {
+                    l.text = format!("Page: {title}");
+                }
+            })
+            .wrap()
+    ])
+    .constraints(vec![Constraint::Length(1), Constraint::Fill(1)])
+    .run()
+}
@@ -0,0 +1,78 @@
+use simple_tui::functions::*;
+
+#[derive(Clone)]
+enum Msg {
+    Edit(String),
+    Add,
+    Select(usize),
+    Remove,
+    ShowCount(bool),
+}
+
+#[derive(Default)]
+struct Todo {
+    draft: String,
+    items: Vec<String>,
+    selected: Option<usize>,
+    show_count: bool,
+}
+
+impl App for Todo {
+    type Msg = Msg;
+
+    fn update(&mut self, msg: Msg) {
+        match msg {
+            Msg::Edit(text) => self.draft = text,
+            Msg::Add if !self.draft.is_empty() => self.items.push(std::mem::take(&mut self.draft)),
+            Msg::Add => {}
+            Msg::Select(i) => self.selected = Some(i),
+            Msg::Remove => {
+                if let Some(i) = self.selected.take() {
+                    self.items.remove(i);
+                }
+            }
+            Msg::ShowCount(on) => self.show_count = on,
+        }
+    }
+
+    fn view(&self) -> Box<dyn Widget> {
+        let mut items = List::new(self.items.clone())
+            .on_select_msg(|i, _| Msg::Select(i))
+            .wrap();
+        if let Some(i) = self.selected {
+            items = items.select(i);
+        }
+        let status = match self.show_count {
+            true => format!("{} items", self.items.len()),
+            false => String::new(),
+        };
+        vbox(widgets![
+            text_input()
+                .text(self.draft.clone())
+                .placeholder("New item, Enter to add")
+                .on_change_msg(|text| Msg::Edit(text.to_string()))
+                .on_submit_msg(|_| Msg::Add),
+            items,
+            hbox(widgets![
+                button("Add").on_click_msg(Msg::Add),
+                button("Remove").on_click_msg(Msg::Remove)
+            ]),
+            toggle("Count")
+                .on(self.show_count)
+                .on_change_msg(Msg::ShowCount),
+            Lable::new(status)
+        ])
+        .constraints(vec![
+            Constraint::Length(3),
+            Constraint::Fill(1),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .as_widget()
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    Todo::default().run()
+}
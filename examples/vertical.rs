@@ -1,6 +1,6 @@
 use simple_tui::functions::*;
 
-fn main() {
+fn main() -> std::io::Result<()> {
     vbox(widgets![
         lable("Hello 1").align(Alignment::Left),
         lable("Hello 2").align(Alignment::Center),
@@ -9,5 +9,5 @@ fn main() {
         lable("Hello 5").align(Alignment::Center).wrap(),
         lable("Hello 6").align(Alignment::Left).wrap()
     ])
-    .start();
+    .run()
 }
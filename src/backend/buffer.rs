@@ -0,0 +1,234 @@
+use crate::{grapheme_width, text_width, Rect, Span, Style};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// * One cell of the terminal grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    /// Text drawn in the cell.
+    pub symbol: String,
+
+    /// Colors and attributes.
+    pub style: Style,
+}
+
+impl Cell {
+    /// * Replace the cell's content.
+    pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
+        self.symbol.clear();
+        self.symbol.push_str(symbol);
+        self
+    }
+
+    /// * Draw `style` over the cell's style.
+    pub fn set_style(&mut self, style: Style) -> &mut Self {
+        self.style = self.style.patch(style);
+        self
+    }
+
+    /// * Reset the cell to an empty space without style.
+    pub fn reset(&mut self) {
+        self.set_symbol(" ");
+        self.style = Style::default();
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".into(),
+            style: Style::default(),
+        }
+    }
+}
+
+/// * Grid of cells that Widgets render into.
+/// * The `Terminal` keeps two of them and only sends the cells that changed
+///   between frames to the `Backend`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Buffer {
+    /// Grid width.
+    width: u16,
+
+    /// Grid height.
+    height: u16,
+
+    /// Cells, row by row.
+    content: Vec<Cell>,
+
+    /// Where the terminal cursor is shown, hidden when `None`.
+    cursor: Option<(u16, u16)>,
+}
+
+impl Buffer {
+    /// * Construct new buffer of `width` x `height` empty cells.
+    pub fn empty(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            content: vec![Cell::default(); width as usize * height as usize],
+            cursor: None,
+        }
+    }
+
+    /// Grid width.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Grid height.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// * Whole buffer area.
+    pub fn area(&self) -> Rect {
+        Rect::new(0.0, 0.0, self.width as f32, self.height as f32)
+    }
+
+    fn index_of(&self, x: u16, y: u16) -> Option<usize> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width as usize + x as usize)
+    }
+
+    /// * Cell at (x, y), `None` when outside the grid.
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index_of(x, y).map(|i| &self.content[i])
+    }
+
+    /// * Mutable cell at (x, y), `None` when outside the grid.
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        self.index_of(x, y).map(|i| &mut self.content[i])
+    }
+
+    /// * Draw `text` starting at `pos` (column, row), keeping the cells' style.
+    /// * Anything outside the grid is clipped.
+    pub fn draw(&mut self, pos: (f32, f32), text: &str) {
+        self.draw_styled(pos, text, Style::default());
+    }
+
+    /// * Draw `spans` one after the other starting at `pos` (column, row).
+    pub fn draw_spans(&mut self, pos: (f32, f32), spans: &[Span]) {
+        let mut x = pos.0;
+        for span in spans {
+            self.draw_styled((x, pos.1), &span.text, span.style);
+            x += text_width(&span.text) as f32;
+        }
+    }
+
+    /// * Draw `style` over every cell of `rect`.
+    pub fn set_style(&mut self, rect: Rect, style: Style) {
+        let (x0, y0) = (rect.x.max(0.0) as u16, rect.y.max(0.0) as u16);
+        let (x1, y1) = (
+            (rect.x + rect.w).max(0.0) as u16,
+            (rect.y + rect.h).max(0.0) as u16,
+        );
+        for y in y0..y1.min(self.height) {
+            for x in x0..x1.min(self.width) {
+                if let Some(cell) = self.get_mut(x, y) {
+                    cell.set_style(style);
+                }
+            }
+        }
+    }
+
+    /// * Reset every cell of `rect` to an empty space without style.
+    pub fn clear(&mut self, rect: Rect) {
+        let (x0, y0) = (rect.x.max(0.0) as u16, rect.y.max(0.0) as u16);
+        let (x1, y1) = (
+            (rect.x + rect.w).max(0.0) as u16,
+            (rect.y + rect.h).max(0.0) as u16,
+        );
+        for y in y0..y1.min(self.height) {
+            for x in x0..x1.min(self.width) {
+                if let Some(cell) = self.get_mut(x, y) {
+                    cell.reset();
+                }
+            }
+        }
+    }
+
+    /// * Draw `text` starting at `pos` (column, row), with `style` over the cells' style.
+    /// * Anything outside the grid is clipped.
+    pub fn draw_styled(&mut self, pos: (f32, f32), text: &str, style: Style) {
+        let (x, y) = (pos.0.floor(), pos.1.floor());
+        if y < 0.0 || y >= self.height as f32 {
+            return;
+        }
+        let mut cx = x;
+        for g in text.graphemes(true) {
+            let width = grapheme_width(g);
+            if width == 0 {
+                continue;
+            }
+            let start = cx;
+            cx += width as f32;
+            if start < 0.0 {
+                continue;
+            }
+            // A wide grapheme that doesn't fit is not drawn at all.
+            if start + width as f32 > self.width as f32 {
+                break;
+            }
+            let (col, row) = (start as u16, y as u16);
+            if let Some(cell) = self.get_mut(col, row) {
+                cell.set_symbol(g).set_style(style);
+            }
+            // The terminal draws wide graphemes over the next cells, keep them empty.
+            for i in 1..width as u16 {
+                if let Some(cell) = self.get_mut(col + i, row) {
+                    cell.set_symbol("").set_style(style);
+                }
+            }
+        }
+    }
+
+    /// * Show the terminal cursor at `pos` (column, row) after this frame.
+    pub fn set_cursor(&mut self, x: f32, y: f32) {
+        if x >= 0.0 && y >= 0.0 && x < self.width as f32 && y < self.height as f32 {
+            self.cursor = Some((x as u16, y as u16));
+        }
+    }
+
+    /// * Hide the terminal cursor after this frame.
+    pub fn hide_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// * Cursor position, `None` when hidden.
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// * Reset every cell and hide the cursor.
+    pub fn reset(&mut self) {
+        self.content.iter_mut().for_each(Cell::reset);
+        self.cursor = None;
+    }
+
+    /// * Change the grid size, the content is cleared.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        *self = Self::empty(width, height);
+    }
+
+    /// * Cells of `other` that differ from `self`, as (x, y, cell).
+    pub fn diff<'a>(&self, other: &'a Buffer) -> Vec<(u16, u16, &'a Cell)> {
+        let width = other.width.max(1) as usize;
+        other
+            .content
+            .iter()
+            .enumerate()
+            .filter(|(i, cell)| self.content.get(*i) != Some(cell) || self.width != other.width)
+            .map(|(i, cell)| ((i % width) as u16, (i / width) as u16, cell))
+            .collect()
+    }
+
+    /// * Buffer content, one `String` per row.
+    pub fn lines(&self) -> Vec<String> {
+        self.content
+            .chunks(self.width.max(1) as usize)
+            .map(|row| row.iter().map(|c| c.symbol.as_str()).collect())
+            .collect()
+    }
+}
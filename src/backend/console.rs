@@ -1,47 +1,138 @@
+use super::{Backend, Cell, Modifier, Style};
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    style::Print,
-    terminal::Clear,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
-use std::io::{stdout, Stdout, Write};
+use std::{
+    io::{self, stdout, Stdout, Write},
+    panic,
+    sync::Once,
+};
+
+/// * Crossterm backend, draws on the real terminal through `stdout`.
 pub struct Console {
     pub stdout: Stdout,
 }
+
 impl Console {
     pub fn new() -> Self {
         Self { stdout: stdout() }
     }
+}
+
+impl Console {
+    /// * Reset the colors and attributes, then apply `style`.
+    fn set_style(&mut self, style: Style) -> io::Result<()> {
+        crossterm::queue!(self.stdout, SetAttribute(Attribute::Reset))?;
+        if let Some(fg) = style.fg {
+            crossterm::queue!(self.stdout, SetForegroundColor(fg))?;
+        }
+        if let Some(bg) = style.bg {
+            crossterm::queue!(self.stdout, SetBackgroundColor(bg))?;
+        }
+        for modifier in Modifier::ALL {
+            if style.modifier.contains(modifier) {
+                crossterm::queue!(self.stdout, SetAttribute(attribute(modifier)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// * Terminal attribute of a single `Modifier`.
+fn attribute(modifier: Modifier) -> Attribute {
+    match modifier {
+        Modifier::BOLD => Attribute::Bold,
+        Modifier::DIM => Attribute::Dim,
+        Modifier::ITALIC => Attribute::Italic,
+        Modifier::UNDERLINED => Attribute::Underlined,
+        Modifier::REVERSED => Attribute::Reverse,
+        _ => Attribute::CrossedOut,
+    }
+}
+
+/// * Leave raw mode and the alternate screen, show the cursor.
+fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    crossterm::execute!(stdout(), DisableMouseCapture, LeaveAlternateScreen, Show)
+}
+
+/// * Restore the terminal before printing the panic message.
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let _ = restore();
+            previous(info);
+        }));
+    });
+}
+
+impl Backend for Console {
+    fn draw(&mut self, content: Vec<(u16, u16, &Cell)>) -> io::Result<()> {
+        let mut last: Option<(u16, u16)> = None;
+        let mut style = Style::default();
+        for (x, y, cell) in content {
+            // Skip `MoveTo` when the cell follows the previous one.
+            if last != Some((x.wrapping_sub(1), y)) {
+                crossterm::queue!(self.stdout, MoveTo(x, y))?;
+            }
+            if cell.style != style {
+                self.set_style(cell.style)?;
+                style = cell.style;
+            }
+            crossterm::queue!(self.stdout, Print(&cell.symbol))?;
+            last = Some((x, y));
+        }
+        if style != Style::default() {
+            crossterm::queue!(self.stdout, SetAttribute(Attribute::Reset))?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdout.flush()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        crossterm::execute!(self.stdout, Clear(ClearType::All))
+    }
 
-    pub fn draw(&mut self, pos: (f32, f32), text: impl Into<String>) {
-        crossterm::queue!(
-            self.stdout,
-            MoveTo(pos.0.floor() as u16, pos.1.floor() as u16)
-        )
-        .unwrap();
-        self.write(text.into());
-        self.flush();
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        crossterm::execute!(self.stdout, Hide)
     }
 
-    pub fn write(&mut self, text: String) {
-        crossterm::queue!(self.stdout, Print(text)).unwrap();
+    fn show_cursor(&mut self) -> io::Result<()> {
+        crossterm::execute!(self.stdout, Show)
     }
 
-    pub fn flush(&mut self) {
-        self.stdout.flush().unwrap();
-        crossterm::execute!(self.stdout, Hide).unwrap();
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        crossterm::execute!(self.stdout, MoveTo(x, y))
     }
 
-    pub fn max_xy(&mut self) -> (f32, f32) {
-        let s = crossterm::terminal::size().unwrap();
-        (s.0 as f32, s.1 as f32)
+    fn enter(&mut self) -> io::Result<()> {
+        install_panic_hook();
+        enable_raw_mode()?;
+        crossterm::execute!(self.stdout, EnterAlternateScreen, EnableMouseCapture, Hide)
     }
 
-    pub fn clear(&mut self) {
-        crossterm::execute!(
-            self.stdout,
-            Clear(crossterm::terminal::ClearType::All),
-            Show
-        )
-        .unwrap();
+    fn leave(&mut self) -> io::Result<()> {
+        restore()
     }
 }
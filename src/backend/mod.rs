@@ -1,2 +1,72 @@
+use crossterm::event::{self, Event};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+mod style;
+pub use style::*;
+
+mod buffer;
+pub use buffer::*;
+
+mod terminal;
+pub use terminal::*;
+
 mod console;
 pub use console::*;
+
+mod test_backend;
+pub use test_backend::*;
+
+/// * Where frames are drawn.
+/// * `Console` draws on the real terminal, `TestBackend` keeps a cell grid in memory.
+pub trait Backend {
+    /// * Draw the given cells (column, row, cell).
+    fn draw(&mut self, content: Vec<(u16, u16, &Cell)>) -> io::Result<()>;
+
+    /// * Make everything drawn so far visible.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// * Size of the drawing area (columns, rows).
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// * Clear the whole drawing area.
+    fn clear(&mut self) -> io::Result<()>;
+
+    /// * Hide the cursor.
+    fn hide_cursor(&mut self) -> io::Result<()>;
+
+    /// * Show the cursor.
+    fn show_cursor(&mut self) -> io::Result<()>;
+
+    /// * Move the cursor to (column, row).
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()>;
+
+    /// * Prepare the drawing area before the application starts.
+    fn enter(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// * Restore the drawing area after the application stops.
+    fn leave(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// * Wait up to `timeout` for an input event, true when one is ready.
+    /// * Reads the real terminal by default.
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        event::poll(timeout)
+    }
+
+    /// * Next input event, waits for it.
+    /// * Reads the real terminal by default.
+    fn read(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+
+    /// * Current time, ticks and timers are measured with it.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
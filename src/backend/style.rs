@@ -0,0 +1,191 @@
+use std::ops::{BitOr, BitOrAssign};
+
+pub use crossterm::style::Color;
+
+/// * Text attributes, combine them with `|`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modifier(u8);
+
+impl Modifier {
+    pub const NONE: Self = Self(0);
+    pub const BOLD: Self = Self(1);
+    pub const DIM: Self = Self(1 << 1);
+    pub const ITALIC: Self = Self(1 << 2);
+    pub const UNDERLINED: Self = Self(1 << 3);
+    pub const REVERSED: Self = Self(1 << 4);
+    pub const CROSSED_OUT: Self = Self(1 << 5);
+
+    /// * Every attribute, one by one.
+    pub const ALL: [Self; 6] = [
+        Self::BOLD,
+        Self::DIM,
+        Self::ITALIC,
+        Self::UNDERLINED,
+        Self::REVERSED,
+        Self::CROSSED_OUT,
+    ];
+
+    /// * Check if all attributes of `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// * Unset the attributes of `other`.
+    pub fn remove(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// * Check if no attribute is set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Modifier {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Modifier {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// * Colors and attributes of a cell.
+/// * `None` colors keep whatever is under them (terminal default at the bottom).
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let ok = Style::new().fg(Color::Green).bold();
+/// let failed = Style::new().fg(Color::Red).add_modifier(Modifier::UNDERLINED);
+///
+/// lable("").spans(vec![
+///     Span::raw("status: "),
+///     Span::styled("3 ok", ok),
+///     Span::raw(", "),
+///     Span::styled("1 failed", failed),
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    /// Foreground (text) color.
+    pub fg: Option<Color>,
+
+    /// Background color.
+    pub bg: Option<Color>,
+
+    /// Text attributes.
+    pub modifier: Modifier,
+}
+
+impl Style {
+    /// * Construct new empty style, changes nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set foreground color.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Set background color.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Add text attributes.
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.modifier |= modifier;
+        self
+    }
+
+    /// Remove text attributes.
+    pub fn remove_modifier(mut self, modifier: Modifier) -> Self {
+        self.modifier = self.modifier.remove(modifier);
+        self
+    }
+
+    /// Bold text.
+    pub fn bold(self) -> Self {
+        self.add_modifier(Modifier::BOLD)
+    }
+
+    /// Dim text.
+    pub fn dim(self) -> Self {
+        self.add_modifier(Modifier::DIM)
+    }
+
+    /// Italic text.
+    pub fn italic(self) -> Self {
+        self.add_modifier(Modifier::ITALIC)
+    }
+
+    /// Underlined text.
+    pub fn underlined(self) -> Self {
+        self.add_modifier(Modifier::UNDERLINED)
+    }
+
+    /// Swap foreground and background.
+    pub fn reversed(self) -> Self {
+        self.add_modifier(Modifier::REVERSED)
+    }
+
+    /// * `other` drawn over `self`: its colors win when set, attributes add up.
+    pub fn patch(self, other: Style) -> Self {
+        Self {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            modifier: self.modifier | other.modifier,
+        }
+    }
+}
+
+/// * Piece of text with its own style.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    /// Text to display
+    pub text: String,
+
+    /// Style of the text.
+    pub style: Style,
+}
+
+impl Span {
+    /// * Construct new span without style.
+    pub fn raw(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// * Construct new styled span.
+    pub fn styled(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+impl From<&str> for Span {
+    fn from(text: &str) -> Self {
+        Self::raw(text)
+    }
+}
+
+impl From<String> for Span {
+    fn from(text: String) -> Self {
+        Self::raw(text)
+    }
+}
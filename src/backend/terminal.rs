@@ -0,0 +1,94 @@
+use super::{Backend, Buffer};
+use crate::Rect;
+use std::io;
+
+/// * Double-buffered drawing on a `Backend`.
+/// * Widgets render into the current `Buffer`, then only the cells that changed
+///   since the previous frame are sent to the backend.
+pub struct Terminal<B: Backend> {
+    /// Where the frames are drawn.
+    backend: B,
+
+    /// Current and previous frames.
+    buffers: [Buffer; 2],
+
+    /// Index of the current frame.
+    current: usize,
+}
+
+impl<B: Backend> Terminal<B> {
+    /// * Construct new terminal with buffers sized to the backend.
+    pub fn new(backend: B) -> io::Result<Self> {
+        let (w, h) = backend.size()?;
+        Ok(Self {
+            backend,
+            buffers: [Buffer::empty(w, h), Buffer::empty(w, h)],
+            current: 0,
+        })
+    }
+
+    /// Backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Mutable backend.
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// * Buffer of the frame being drawn.
+    pub fn current_buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffers[self.current]
+    }
+
+    /// * Resize the buffers and clear the screen, so the next frame is drawn in full.
+    pub fn resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        self.buffers[0].resize(width, height);
+        self.buffers[1].resize(width, height);
+        self.backend.clear()
+    }
+
+    /// * Clear the screen, so the next frame is drawn in full.
+    pub fn clear(&mut self) -> io::Result<()> {
+        self.buffers[1 - self.current].reset();
+        self.backend.clear()
+    }
+
+    /// * Draw one frame.
+    /// * `f` renders into the buffer, then the changed cells are flushed.
+    pub fn draw(&mut self, f: impl FnOnce(Rect, &mut Buffer)) -> io::Result<()> {
+        let (w, h) = self.backend.size()?;
+        if (w, h)
+            != (
+                self.buffers[self.current].width(),
+                self.buffers[self.current].height(),
+            )
+        {
+            self.resize(w, h)?;
+        }
+
+        let buffer = &mut self.buffers[self.current];
+        f(buffer.area(), buffer);
+
+        self.flush()?;
+        self.current = 1 - self.current;
+        self.buffers[self.current].reset();
+        Ok(())
+    }
+
+    /// * Send the cells that changed since the previous frame to the backend.
+    fn flush(&mut self) -> io::Result<()> {
+        let previous = &self.buffers[1 - self.current];
+        let current = &self.buffers[self.current];
+        self.backend.draw(previous.diff(current))?;
+        match current.cursor() {
+            Some((x, y)) => {
+                self.backend.set_cursor(x, y)?;
+                self.backend.show_cursor()?;
+            }
+            None => self.backend.hide_cursor()?,
+        }
+        self.backend.flush()
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Backend, Buffer, Cell};
+use crossterm::event::Event;
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+/// * Queued input, an event or time passing.
+enum Input {
+    Event(Event),
+    Wait(Duration),
+}
+
+/// * Headless backend, records everything drawn in a grid of cells.
+/// * Useful to assert on rendered Widgets without a real terminal.
+/// * Input comes from a queue (`push_event`, `push_wait`) and time only passes in `push_wait`,
+///   so a `Runner` can be tested with ticks and timers; reading past the queue is an error.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+/// let mut l = lable("Hello").wrap();
+/// terminal
+///     .draw(|_, buf| l.render(Rect::new(0.0, 0.0, 10.0, 3.0), buf))
+///     .unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec!["┌────────┐", "│Hello   │", "└────────┘"]
+/// );
+/// ```
+pub struct TestBackend {
+    /// Cells drawn so far.
+    buffer: Buffer,
+
+    /// Cursor position.
+    cursor: (u16, u16),
+
+    /// Cursor visibility.
+    cursor_visible: bool,
+
+    /// Input not read yet.
+    input: VecDeque<Input>,
+
+    /// When the clock started.
+    start: Instant,
+
+    /// Time passed so far.
+    elapsed: Duration,
+}
+
+impl TestBackend {
+    /// * Construct new empty grid of `width` x `height` cells.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::empty(width, height),
+            cursor: (0, 0),
+            cursor_visible: true,
+            input: VecDeque::new(),
+            start: Instant::now(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// * Queue `event`, read after the input queued before it.
+    pub fn push_event(&mut self, event: Event) {
+        self.input.push_back(Input::Event(event));
+    }
+
+    /// * Let `duration` pass before the input queued after it.
+    pub fn push_wait(&mut self, duration: Duration) {
+        self.input.push_back(Input::Wait(duration));
+    }
+
+    /// * Time passed since the backend was made.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// * Cells drawn so far.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// * Grid content, one `String` per row.
+    pub fn lines(&self) -> Vec<String> {
+        self.buffer.lines()
+    }
+
+    /// * Cursor position.
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    /// * Check if the cursor is visible.
+    pub fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// * Change the grid size, the content is cleared.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.buffer.resize(width, height);
+    }
+}
+
+impl Backend for TestBackend {
+    fn draw(&mut self, content: Vec<(u16, u16, &Cell)>) -> io::Result<()> {
+        for (x, y, cell) in content {
+            if let Some(c) = self.buffer.get_mut(x, y) {
+                *c = cell.clone();
+            }
+            self.cursor = (x + 1, y);
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok((self.buffer.width(), self.buffer.height()))
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.buffer.reset();
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.cursor_visible = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.cursor = (x, y);
+        Ok(())
+    }
+
+    fn poll(&mut self, mut timeout: Duration) -> io::Result<bool> {
+        loop {
+            match self.input.front_mut() {
+                Some(Input::Event(_)) => return Ok(true),
+                Some(Input::Wait(wait)) if *wait > timeout => {
+                    *wait -= timeout;
+                    self.elapsed += timeout;
+                    return Ok(false);
+                }
+                Some(Input::Wait(wait)) => {
+                    timeout -= *wait;
+                    self.elapsed += *wait;
+                    self.input.pop_front();
+                }
+                None => return Err(io::ErrorKind::UnexpectedEof.into()),
+            }
+        }
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        loop {
+            match self.input.pop_front() {
+                Some(Input::Event(event)) => return Ok(event),
+                Some(Input::Wait(wait)) => self.elapsed += wait,
+                None => return Err(io::ErrorKind::UnexpectedEof.into()),
+            }
+        }
+    }
+
+    fn now(&self) -> Instant {
+        self.start + self.elapsed
+    }
+}
@@ -4,8 +4,13 @@ pub use widgets::*;
 mod backend;
 pub use backend::*;
 
+mod runner;
+pub use runner::*;
+
+pub use crossterm;
+
 /// * Call .as_widget() over items
-/// * Convert `Vec<T>` => `Vec<Option<Widget>>`
+/// * Convert `Vec<T>` => `Vec<Box<dyn Widget>>`
 #[macro_export]
 macro_rules! widgets {
     [$($item:expr),*] => {
@@ -17,12 +22,12 @@ pub mod functions {
     pub use super::*;
 
     /// * Construct new Vertiacl Box.
-    pub fn vbox(widgets: Vec<Option<Widget>>) -> Vbox {
+    pub fn vbox(widgets: Vec<Box<dyn Widget>>) -> Vbox {
         Vbox::new(widgets)
     }
 
     /// * Construct new Horizontal Box.
-    pub fn hbox(widgets: Vec<Option<Widget>>) -> Hbox {
+    pub fn hbox(widgets: Vec<Box<dyn Widget>>) -> Hbox {
         Hbox::new(widgets)
     }
 
@@ -35,4 +40,89 @@ pub mod functions {
     pub fn button(text: &'static str) -> Button {
         Button::new(text)
     }
+
+    /// * Construct new Block with borders on every side around `widget`.
+    pub fn block(widget: impl AsWidget) -> Block {
+        Block::bordered().child(widget)
+    }
+
+    /// * Construct new ScrollView around `widget`.
+    pub fn scroll_view(widget: impl AsWidget) -> ScrollView {
+        ScrollView::new(widget)
+    }
+
+    /// * Construct new Tabs, add pages with `.tab(title, widget)`.
+    pub fn tabs() -> Tabs {
+        Tabs::new()
+    }
+
+    /// * Construct new Overlay without layers over `widget`.
+    pub fn overlay(widget: impl AsWidget) -> Overlay {
+        Overlay::new(widget)
+    }
+
+    /// * Construct new Dialog with `OK` and `Cancel` buttons.
+    pub fn dialog(title: &'static str, message: &'static str) -> Dialog {
+        Dialog::new(title, message)
+    }
+
+    /// * Construct new List.
+    pub fn list(items: Vec<&'static str>) -> List {
+        List::new(items)
+    }
+
+    /// * Construct new Table with its header.
+    pub fn table(header: Vec<&'static str>) -> Table {
+        Table::new(header)
+    }
+
+    /// * Construct new Checkbox.
+    pub fn checkbox(text: &'static str) -> Checkbox {
+        Checkbox::new(text)
+    }
+
+    /// * Construct new Toggle.
+    pub fn toggle(text: &'static str) -> Toggle {
+        Toggle::new(text)
+    }
+
+    /// * Construct new RadioGroup.
+    pub fn radio_group(options: Vec<&'static str>) -> RadioGroup {
+        RadioGroup::new(options)
+    }
+
+    /// * Construct new ProgressBar.
+    pub fn progress_bar() -> ProgressBar {
+        ProgressBar::new()
+    }
+
+    /// * Construct new Gauge.
+    pub fn gauge() -> Gauge {
+        Gauge::new()
+    }
+
+    /// * Construct new Sparkline.
+    pub fn sparkline(data: Vec<u64>) -> Sparkline {
+        Sparkline::new(data)
+    }
+
+    /// * Construct new BarChart.
+    pub fn bar_chart(bars: Vec<(&'static str, u64)>) -> BarChart {
+        BarChart::new(bars)
+    }
+
+    /// * Construct new Canvas.
+    pub fn canvas() -> Canvas {
+        Canvas::new()
+    }
+
+    /// * Construct new LineChart.
+    pub fn line_chart(datasets: Vec<Dataset>) -> LineChart {
+        LineChart::new(datasets)
+    }
+
+    /// * Construct new TextInput.
+    pub fn text_input() -> TextInput {
+        TextInput::new()
+    }
 }
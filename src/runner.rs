@@ -0,0 +1,416 @@
+use crate::{
+    route, tick, App, Backend, Console, Context, LookupError, Program, Store, Terminal, Widget,
+};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+/// * Callback run by a timer, what it wants to do is pushed into the `Context`.
+type TimerCallback = Box<dyn FnMut(&mut Context)>;
+
+/// * Callback waiting for its time, once or periodically.
+struct Timer {
+    /// Time from the start of the loop to the first run.
+    delay: Duration,
+
+    /// Time between two runs, `None` for a one-shot timer.
+    period: Option<Duration>,
+
+    /// Next run.
+    due: Instant,
+
+    f: TimerCallback,
+}
+
+/// * What woke the event loop up.
+enum Step {
+    /// Input event for the Widgets.
+    Event(Event),
+
+    /// The tick rate elapsed.
+    Tick,
+
+    /// Timers ran, with what they want to do.
+    Timers(Context),
+}
+
+/// * Event loop: draw the root Widget, wait for an event, hand it to the root, repeat.
+/// * Without a tick rate or timers it sleeps until the next input event; with them it also
+///   wakes up to deliver ticks (`Widget::on_tick`, `App::on_tick`) and run the timers.
+/// * Returns when one of the quit keys is pressed (`Esc` by default) and no Widget used it,
+///   so a focused `TextInput` still gets the letters of a quit key.
+/// * A callback whose related id isn't in the tree, or a change to a state of another type,
+///   doesn't stop the loop; the error is kept in `errors` instead.
+/// * The terminal is always restored on exit, including on error and panic.
+///
+/// Example:
+///
+/// ```no_run
+/// use simple_tui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::{functions::*, widgets};
+/// use std::time::Duration;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut root = vbox(widgets![lable("Press q to quit").set_id("status")]);
+///
+///     Runner::new()?
+///         .quit_on(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE))
+///         .after(Duration::from_secs(10), |ctx: &mut Context| {
+///             ctx.trigger("status", |w: &mut dyn Widget| {
+///                 if let Some(l) = w.downcast_mut::<Lable>() {
+///                     l.text = "Still here? Press q to quit".into();
+///                 }
+///             })
+///         })
+///         .run(&mut root)?;
+///
+///     // `root` is still here, read whatever you need from it.
+///     Ok(())
+/// }
+/// ```
+pub struct Runner<B: Backend> {
+    /// Where the Widgets are drawn.
+    terminal: Terminal<B>,
+
+    /// Keys that stop the loop.
+    quit_keys: Vec<KeyEvent>,
+
+    /// Time between two ticks, no ticks when `None`.
+    tick_rate: Option<Duration>,
+
+    /// Next tick.
+    next_tick: Option<Instant>,
+
+    /// One-shot and repeating timers.
+    timers: Vec<Timer>,
+
+    /// Callbacks that couldn't run, see `errors`.
+    errors: Vec<LookupError>,
+}
+
+impl Runner<Console> {
+    /// * Construct new runner drawing on the real terminal.
+    pub fn new() -> io::Result<Self> {
+        Self::with_backend(Console::new())
+    }
+}
+
+impl<B: Backend> Runner<B> {
+    /// * Construct new runner drawing on `backend`.
+    pub fn with_backend(backend: B) -> io::Result<Self> {
+        Ok(Self {
+            terminal: Terminal::new(backend)?,
+            quit_keys: Vec::new(),
+            tick_rate: None,
+            next_tick: None,
+            timers: Vec::new(),
+            errors: Vec::new(),
+        })
+    }
+
+    /// * Stop the loop when `key` is pressed, replaces the default `Esc`.
+    /// * Can be called several times to allow several keys.
+    /// * Keys used by a Widget don't stop the loop, like letters typed in a focused `TextInput`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use simple_tui::functions::*;
+    ///
+    /// let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    /// let mut backend = TestBackend::new(10, 4);
+    /// backend.push_event(key(KeyCode::Tab));
+    /// for c in "quit".chars() {
+    ///     backend.push_event(key(KeyCode::Char(c)));
+    /// }
+    /// // on the button, `q` isn't used any more.
+    /// backend.push_event(key(KeyCode::Tab));
+    /// backend.push_event(key(KeyCode::Char('q')));
+    ///
+    /// let mut root = vbox(widgets![text_input(), button("OK")]);
+    /// Runner::with_backend(backend)
+    ///     .unwrap()
+    ///     .quit_on(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE))
+    ///     .run(&mut root)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(root.widgets[0].downcast_ref::<TextInput>().unwrap().text, "quit");
+    /// ```
+    pub fn quit_on(mut self, key: KeyEvent) -> Self {
+        self.quit_keys.push(key);
+        self
+    }
+
+    /// * Deliver a tick every `rate`, to every visible Widget and to the `App`.
+    /// * The screen is drawn again after each tick.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use simple_tui::functions::*;
+    /// use std::time::Duration;
+    ///
+    /// struct Ticks {
+    ///     count: u32,
+    ///     shape: Rect,
+    /// }
+    ///
+    /// impl Widget for Ticks {
+    ///     fn render(&mut self, rect: Rect, _buf: &mut Buffer) {
+    ///         self.shape = rect;
+    ///     }
+    ///
+    ///     fn shape(&self) -> &Rect {
+    ///         &self.shape
+    ///     }
+    ///
+    ///     fn on_tick(&mut self, _ctx: &mut Context) {
+    ///         self.count += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut backend = TestBackend::new(10, 1);
+    /// backend.push_wait(Duration::from_millis(360));
+    /// backend.push_event(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+    ///
+    /// let mut root = vbox(widgets![Ticks { count: 0, shape: Rect::default() }]);
+    /// Runner::with_backend(backend)
+    ///     .unwrap()
+    ///     .tick_rate(Duration::from_millis(50))
+    ///     .run(&mut root)
+    ///     .unwrap();
+    ///
+    /// // At 50, 100, .., 350 ms.
+    /// assert_eq!(root.widgets[0].downcast_ref::<Ticks>().unwrap().count, 7);
+    /// ```
+    pub fn tick_rate(mut self, rate: Duration) -> Self {
+        self.tick_rate = Some(rate);
+        self
+    }
+
+    /// * Run `f` once, `delay` after the loop started.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use simple_tui::functions::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut backend = TestBackend::new(10, 1);
+    /// backend.push_wait(Duration::from_secs(5));
+    /// backend.push_event(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+    ///
+    /// let mut store = Store::new(0);
+    /// let mut runner = Runner::with_backend(backend)
+    ///     .unwrap()
+    ///     .after(Duration::from_secs(1), |ctx: &mut Context| {
+    ///         ctx.update(|n: &mut i32| *n += 1)
+    ///     })
+    ///     .after(Duration::from_secs(2), |ctx: &mut Context| {
+    ///         ctx.trigger("missing", |_: &mut dyn Widget| {})
+    ///     });
+    /// runner.run_with(&mut lable(""), &mut store).unwrap();
+    ///
+    /// assert_eq!(store.state, 1);
+    /// // The missing Widget didn't stop the loop.
+    /// assert_eq!(runner.errors(), [LookupError::NotFound("missing".into_id())]);
+    /// assert_eq!(runner.terminal().backend().elapsed(), Duration::from_secs(5));
+    /// ```
+    pub fn after(mut self, delay: Duration, f: impl FnOnce(&mut Context) + 'static) -> Self {
+        let mut f = Some(f);
+        self.timers.push(Timer {
+            delay,
+            period: None,
+            due: self.terminal.backend().now() + delay,
+            f: Box::new(move |ctx| {
+                if let Some(f) = f.take() {
+                    f(ctx)
+                }
+            }),
+        });
+        self
+    }
+
+    /// * Run `f` every `period`, starting one `period` after the loop started.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use simple_tui::functions::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut backend = TestBackend::new(10, 1);
+    /// backend.push_wait(Duration::from_millis(350));
+    /// backend.push_event(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+    ///
+    /// let mut store = Store::new(0);
+    /// Runner::with_backend(backend)
+    ///     .unwrap()
+    ///     .every(Duration::from_millis(100), |ctx: &mut Context| {
+    ///         ctx.update(|n: &mut i32| *n += 1)
+    ///     })
+    ///     .run_with(&mut lable(""), &mut store)
+    ///     .unwrap();
+    ///
+    /// // At 100, 200 and 300 ms.
+    /// assert_eq!(store.state, 3);
+    /// ```
+    pub fn every(mut self, period: Duration, f: impl FnMut(&mut Context) + 'static) -> Self {
+        self.timers.push(Timer {
+            delay: period,
+            period: Some(period),
+            due: self.terminal.backend().now() + period,
+            f: Box::new(f),
+        });
+        self
+    }
+
+    /// * Callbacks that couldn't run while the loop was running, oldest first.
+    /// * Their related id wasn't in the tree, or the state wasn't of their type;
+    ///   the other callbacks of the same event still ran.
+    pub fn errors(&self) -> &[LookupError] {
+        &self.errors
+    }
+
+    /// Terminal.
+    pub fn terminal(&self) -> &Terminal<B> {
+        &self.terminal
+    }
+
+    /// * Check if `key` is one of the quit keys.
+    pub fn is_quit(&self, key: &KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if self.quit_keys.is_empty() {
+            return key.code == KeyCode::Esc && key.modifiers == KeyModifiers::NONE;
+        }
+        self.quit_keys
+            .iter()
+            .any(|q| q.code == key.code && q.modifiers == key.modifiers)
+    }
+
+    /// * Check if `event` is a press of one of the quit keys.
+    fn is_quit_event(&self, event: &Event) -> bool {
+        matches!(event, Event::Key(key) if self.is_quit(key))
+    }
+
+    /// * Run `root` until a quit key is pressed.
+    pub fn run(&mut self, root: &mut dyn Widget) -> io::Result<()> {
+        self.run_with(root, &mut Store::new(()))
+    }
+
+    /// * Run `root` with the application state of `store` until a quit key is pressed.
+    /// * Bound Widgets are updated from the state before every frame.
+    pub fn run_with<S: 'static>(
+        &mut self,
+        root: &mut dyn Widget,
+        store: &mut Store<S>,
+    ) -> io::Result<()> {
+        self.session(|runner| loop {
+            store.sync(root);
+            runner.terminal.draw(|rect, buf| root.render(rect, buf))?;
+            let result = match runner.next_step()? {
+                Step::Event(event) => match route(root, &event) {
+                    Some((_, false)) if runner.is_quit_event(&event) => return Ok(()),
+                    Some((ctx, _)) => store.apply(root, ctx),
+                    None => Ok(()),
+                },
+                Step::Tick => {
+                    let ctx = tick(root);
+                    store.apply(root, ctx)
+                }
+                Step::Timers(ctx) => store.apply(root, ctx),
+            };
+            runner.errors.extend(result.err());
+        })
+    }
+
+    /// * Run the `App` of `program` until a quit key is pressed.
+    pub fn run_app<A: App>(&mut self, program: &mut Program<A>) -> io::Result<()> {
+        self.session(|runner| loop {
+            runner
+                .terminal
+                .draw(|rect, buf| program.render(rect, buf))?;
+            let result = match runner.next_step()? {
+                Step::Event(event) => match route(program.root_mut(), &event) {
+                    Some((_, false)) if runner.is_quit_event(&event) => return Ok(()),
+                    Some((ctx, _)) => program.apply(ctx),
+                    None => Ok(()),
+                },
+                Step::Tick => program.tick(),
+                Step::Timers(ctx) => program.apply(ctx),
+            };
+            runner.errors.extend(result.err());
+        })
+    }
+
+    /// * Run `f` on the prepared terminal, restored afterwards.
+    /// * The tick and the timers start counting now.
+    fn session(&mut self, f: impl FnOnce(&mut Self) -> io::Result<()>) -> io::Result<()> {
+        let now = self.terminal.backend().now();
+        self.next_tick = self.tick_rate.map(|rate| now + rate);
+        for timer in &mut self.timers {
+            timer.due = now + timer.delay;
+        }
+
+        self.terminal.backend_mut().enter()?;
+        let result = self.terminal.clear().and_then(|_| f(self));
+        let restored = self.terminal.backend_mut().leave();
+        result.and(restored)
+    }
+
+    /// * Wait for the next input event, tick or timer, whichever comes first.
+    fn next_step(&mut self) -> io::Result<Step> {
+        loop {
+            let now = self.terminal.backend().now();
+            if self.timers.iter().any(|t| t.due <= now) {
+                return Ok(Step::Timers(self.run_timers(now)));
+            }
+            if let (Some(due), Some(rate)) = (self.next_tick, self.tick_rate) {
+                if due <= now {
+                    self.next_tick = Some(now + rate);
+                    return Ok(Step::Tick);
+                }
+            }
+
+            let deadline = self
+                .timers
+                .iter()
+                .map(|t| t.due)
+                .chain(self.next_tick)
+                .min();
+            if let Some(deadline) = deadline {
+                if !self.terminal.backend_mut().poll(deadline - now)? {
+                    continue;
+                }
+            }
+            return Ok(Step::Event(self.terminal.backend_mut().read()?));
+        }
+    }
+
+    /// * Run the timers that are due, drop the one-shot ones.
+    fn run_timers(&mut self, now: Instant) -> Context {
+        let mut ctx = Context::new();
+        self.timers.retain_mut(|timer| {
+            if timer.due > now {
+                return true;
+            }
+            (timer.f)(&mut ctx);
+            match timer.period {
+                Some(period) => {
+                    timer.due = now + period;
+                    true
+                }
+                None => false,
+            }
+        });
+        ctx
+    }
+}
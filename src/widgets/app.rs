@@ -0,0 +1,269 @@
+use super::{focus_index, route, set_focus_index, tick, Context, LookupError, Widget};
+use crate::{Buffer, Rect, Runner};
+use crossterm::event::Event;
+use std::{any::type_name, io};
+
+/// * Elm-style application: the model changes only through messages,
+///   and the Widget tree is a function of the model.
+/// * Controls emit messages (`Button::on_click_msg`, `Checkbox::on_change_msg`, `ctx.send(..)`, ...)
+///   instead of running callbacks, so `update` can be tested as a plain function.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// #[derive(Clone)]
+/// enum Msg {
+///     Add(i64),
+/// }
+///
+/// struct Counter(i64);
+///
+/// impl App for Counter {
+///     type Msg = Msg;
+///
+///     fn update(&mut self, msg: Msg) {
+///         match msg {
+///             Msg::Add(n) => self.0 += n,
+///         }
+///     }
+///
+///     fn view(&self) -> Box<dyn Widget> {
+///         vbox(widgets![
+///             Lable::new(format!("Counter: {}", self.0)),
+///             button("+1").on_click_msg(Msg::Add(1))
+///         ])
+///         .as_widget()
+///     }
+/// }
+///
+/// // no terminal needed.
+/// let mut counter = Counter(0);
+/// counter.update(Msg::Add(2));
+/// assert_eq!(counter.0, 2);
+/// ```
+pub trait App {
+    /// * What the controls send to `update`.
+    type Msg: 'static;
+
+    /// * Change the model after a message.
+    fn update(&mut self, msg: Self::Msg);
+
+    /// * Widget tree showing the model, built again after every `update`.
+    fn view(&self) -> Box<dyn Widget>;
+
+    /// * Message given to `update` on every tick of the event loop, see `Runner::tick_rate`.
+    fn on_tick(&self) -> Option<Self::Msg> {
+        None
+    }
+
+    /// #####  Start rendering the application on screen until `Esc` is pressed.
+    /// * Use `Runner::run_app` to choose the quit key or the backend.
+    fn run(self) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        Runner::new()?.run_app(&mut Program::new(self))
+    }
+}
+
+/// * Runs an `App`: sends events through its view, gives the messages to `update`,
+///   and builds the view again, keeping the focus at the same place.
+/// * Works without a terminal, to test the whole application.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::functions::*;
+///
+/// struct Todo {
+///     done: bool,
+/// }
+///
+/// impl App for Todo {
+///     type Msg = bool;
+///
+///     fn update(&mut self, done: bool) {
+///         self.done = done;
+///     }
+///
+///     fn view(&self) -> Box<dyn Widget> {
+///         checkbox("Write tests")
+///             .checked(self.done)
+///             .on_change_msg(|done| done)
+///             .as_widget()
+///     }
+/// }
+///
+/// let mut program = Program::new(Todo { done: false });
+/// let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+/// program.dispatch(&key(KeyCode::Tab)).unwrap();
+/// program.dispatch(&key(KeyCode::Char(' '))).unwrap();
+///
+/// assert!(program.app.done);
+/// assert!(program.root().is_focused());
+/// ```
+pub struct Program<A: App> {
+    /// The application, its model.
+    pub app: A,
+
+    /// Current view.
+    root: Box<dyn Widget>,
+}
+
+impl<A: App> Program<A> {
+    /// * Construct new program, with the first view of `app`.
+    pub fn new(app: A) -> Self {
+        let root = app.view();
+        Self { app, root }
+    }
+
+    /// Current view.
+    pub fn root(&self) -> &dyn Widget {
+        self.root.as_ref()
+    }
+
+    /// Mutable current view.
+    pub fn root_mut(&mut self) -> &mut dyn Widget {
+        self.root.as_mut()
+    }
+
+    /// * Give `msg` to `update`, then build the view again.
+    pub fn send(&mut self, msg: A::Msg) {
+        self.app.update(msg);
+        self.refresh();
+    }
+
+    /// * Send one input event through the view, then run the callbacks.
+    /// * When messages were sent, they go to `update` in order, then the view is built again.
+    pub fn dispatch(&mut self, event: &Event) -> Result<(), LookupError> {
+        match route(self.root.as_mut(), event) {
+            Some((ctx, _)) => self.apply(ctx),
+            None => Ok(()),
+        }
+    }
+
+    /// * Give one tick to the view and to `App::on_tick`, then run the callbacks.
+    pub fn tick(&mut self) -> Result<(), LookupError> {
+        let mut ctx = tick(self.root.as_mut());
+        if let Some(msg) = self.app.on_tick() {
+            ctx.send(msg);
+        }
+        self.apply(ctx)
+    }
+
+    /// * Run the callbacks of `ctx` on the view.
+    /// * When messages were sent, they go to `update` in order, then the view is built again.
+    /// * A message that isn't an `App::Msg` (`on_click_msg(Msg::Add)` sends the function `Msg::Add`,
+    ///   not a `Msg`) is dropped; the first error is returned once everything else ran.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::functions::*;
+    ///
+    /// struct Counter(i64);
+    ///
+    /// impl App for Counter {
+    ///     type Msg = i64;
+    ///
+    ///     fn update(&mut self, n: i64) {
+    ///         self.0 += n;
+    ///     }
+    ///
+    ///     fn view(&self) -> Box<dyn Widget> {
+    ///         lable("").as_widget()
+    ///     }
+    /// }
+    ///
+    /// let mut program = Program::new(Counter(0));
+    /// let mut ctx = Context::new();
+    /// ctx.send(1_i32); // not an `i64`
+    /// ctx.send(2_i64);
+    ///
+    /// assert_eq!(program.apply(ctx), Err(LookupError::WrongMessage("i64")));
+    /// assert_eq!(program.app.0, 2);
+    /// ```
+    pub fn apply(&mut self, mut ctx: Context) -> Result<(), LookupError> {
+        let messages = ctx.take_messages();
+        let mut result = ctx.apply(self.root.as_mut());
+        if !messages.is_empty() {
+            for msg in messages {
+                match msg.downcast::<A::Msg>() {
+                    Ok(msg) => self.app.update(*msg),
+                    Err(_) => {
+                        let error = LookupError::WrongMessage(type_name::<A::Msg>());
+                        result = result.and(Err(error));
+                    }
+                }
+            }
+            self.refresh();
+        }
+        result
+    }
+
+    /// * Build the view again, the focus stays on the same focusable Widget.
+    /// * Widgets at the same place and of the same type keep their local state (`Widget::restore`):
+    ///   the cursor of a `TextInput`, the selection of a `List` or `Table`, the sort order of a `Table`,
+    ///   the scroll offset of a `List`, `Table` or `ScrollView`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use simple_tui::functions::*;
+    ///
+    /// struct Note(String);
+    ///
+    /// impl App for Note {
+    ///     type Msg = String;
+    ///
+    ///     fn update(&mut self, text: String) {
+    ///         self.0 = text;
+    ///     }
+    ///
+    ///     fn view(&self) -> Box<dyn Widget> {
+    ///         text_input()
+    ///             .text(self.0.as_str())
+    ///             .on_change_msg(|text: &str| text.to_string())
+    ///             .as_widget()
+    ///     }
+    /// }
+    ///
+    /// let mut program = Program::new(Note("abc".into()));
+    /// let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    /// program.dispatch(&key(KeyCode::Tab)).unwrap();
+    /// for code in [KeyCode::Left, KeyCode::Left, KeyCode::Char('X'), KeyCode::Char('Y')] {
+    ///     program.dispatch(&key(code)).unwrap();
+    /// }
+    ///
+    /// // The cursor stayed after `X` while the view was built again.
+    /// assert_eq!(program.app.0, "aXYbc");
+    /// ```
+    pub fn refresh(&mut self) {
+        let focus = focus_index(self.root.as_ref());
+        let old = std::mem::replace(&mut self.root, self.app.view());
+        restore(self.root.as_mut(), old.as_ref());
+        if let Some(i) = focus {
+            set_focus_index(self.root.as_mut(), i);
+        }
+    }
+
+    /// Render the current view into the buffer.
+    pub fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        self.root.render(rect, buf);
+    }
+}
+
+/// * Give every Widget under `new` the local state of the Widget at the same place under `old`.
+fn restore(new: &mut dyn Widget, old: &dyn Widget) {
+    if new.as_any().type_id() != old.as_any().type_id() {
+        return;
+    }
+    new.restore(old);
+    for (new, old) in new.all_children_mut().into_iter().zip(old.all_children()) {
+        restore(new, old);
+    }
+}
@@ -0,0 +1,201 @@
+use super::{eighths, sparkline::column, IntoId, Rect, Widget};
+use crate::{text_width, truncate, Alignment, Block, Buffer, Style};
+
+/// * Vertical bars with a label under each, scaled to the max value (or `.max(..)`).
+/// * Bars share the width unless `.bar_width(..)` is set, those that don't fit are left out.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(11, 4)).unwrap();
+/// let mut chart = bar_chart(vec![("Mon", 2), ("Tue", 6), ("Wed", 4)]);
+/// terminal.draw(|rect, buf| chart.render(rect, buf)).unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec!["    ███    ", "    ███ ███", "█2█ █6█ █4█", "Mon Tue Wed"]
+/// );
+/// ```
+pub struct BarChart {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Labels and values, left to right.
+    pub bars: Vec<(String, u64)>,
+
+    /// Value of a full bar, the max of the data by default.
+    max: Option<u64>,
+
+    /// Columns per bar, shared from the width by default.
+    bar_width: Option<usize>,
+
+    /// Columns between bars.
+    bar_gap: usize,
+
+    /// Style of the bars, drawn over the widget style.
+    bar_style: Style,
+
+    /// Style of the values, drawn over the bar style.
+    value_style: Style,
+
+    /// Style of the labels, drawn over the widget style.
+    label_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl BarChart {
+    /// * Construct new bar chart.
+    pub fn new<T: Into<String>>(bars: Vec<(T, u64)>) -> Self {
+        Self {
+            id: None,
+            bars: bars.into_iter().map(|(l, v)| (l.into(), v)).collect(),
+            max: None,
+            bar_width: None,
+            bar_gap: 1,
+            bar_style: Style::default(),
+            value_style: Style::default().reversed(),
+            label_style: Style::default(),
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set the value of a full bar, instead of the max of the data.
+    pub fn max(mut self, max: u64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// * Set the columns per bar, instead of sharing the width.
+    pub fn bar_width(mut self, width: usize) -> Self {
+        self.bar_width = Some(width.max(1));
+        self
+    }
+
+    /// Set the columns between bars, 1 by default.
+    pub fn bar_gap(mut self, gap: usize) -> Self {
+        self.bar_gap = gap;
+        self
+    }
+
+    /// * Set the style of the bars.
+    pub fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// * Set the style of the values, reversed by default to show on the bars.
+    pub fn value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// * Set the style of the labels.
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for BarChart {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(rect, buf);
+                block.inner(rect)
+            }
+            None => rect,
+        };
+        let (width, height) = (content.w.max(0.0) as usize, content.h.max(0.0) as usize);
+        if self.bars.is_empty() || width == 0 || height == 0 {
+            return;
+        }
+        let gap = self.bar_gap;
+        let bar_width = self
+            .bar_width
+            .unwrap_or_else(|| ((width + gap) / self.bars.len()).saturating_sub(gap))
+            .max(1);
+        let max = self
+            .max
+            .unwrap_or_else(|| self.bars.iter().map(|(_, v)| *v).max().unwrap_or(0))
+            .max(1);
+        // last row for the labels.
+        let bar_height = height - 1;
+        let (label_y, bottom) = (
+            content.y + bar_height as f32,
+            content.y + bar_height as f32 - 1.0,
+        );
+
+        let fits = (width + gap) / (bar_width + gap);
+        for (i, (label, value)) in self.bars.iter().take(fits).enumerate() {
+            let x = content.x + (i * (bar_width + gap)) as f32;
+            let filled = eighths(*value, max, bar_height);
+            for (y, symbol) in column(filled, bar_height).into_iter().enumerate() {
+                buf.draw((x, content.y + y as f32), &symbol.repeat(bar_width));
+            }
+            if bar_height > 0 {
+                let area = Rect::new(x, content.y, bar_width as f32, bar_height as f32);
+                buf.set_style(area, self.bar_style);
+            }
+
+            // on the bottom row of the bar, when it fits.
+            let text = value.to_string();
+            if filled >= 8 && text_width(&text) <= bar_width {
+                let offset = Alignment::Center.offset(bar_width, text_width(&text)) as f32;
+                buf.draw_styled((x + offset, bottom), &text, self.value_style);
+            }
+
+            let label = truncate(label, bar_width);
+            let offset = Alignment::Center.offset(bar_width, text_width(&label)) as f32;
+            buf.draw_styled((x + offset, label_y), &label, self.label_style);
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+}
@@ -0,0 +1,373 @@
+use super::{render_child, AsWidget, IntoId, Rect, Widget};
+use crate::{spans_width, truncate_spans, Alignment, Buffer, Context, Span, Style};
+use crossterm::event::Event;
+use std::ops::BitOr;
+
+/// * Sides of a `Block` that have a border, combine them with `|`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Borders(u8);
+
+impl Borders {
+    pub const NONE: Self = Self(0);
+    pub const TOP: Self = Self(1);
+    pub const RIGHT: Self = Self(1 << 1);
+    pub const BOTTOM: Self = Self(1 << 2);
+    pub const LEFT: Self = Self(1 << 3);
+    pub const ALL: Self = Self(0b1111);
+
+    /// * Check if all sides of `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Borders {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// * Characters used to draw a border.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderType {
+    /// `┌─┐│└┘`
+    #[default]
+    Plain,
+
+    /// `╭─╮│╰╯`
+    Rounded,
+
+    /// `╔═╗║╚╝`
+    Double,
+
+    /// `┏━┓┃┗┛`
+    Thick,
+
+    /// `+-+|++`, for terminals without box drawing characters.
+    Ascii,
+}
+
+impl BorderType {
+    /// * Symbols as `[top left, top right, bottom left, bottom right, horizontal, vertical]`.
+    pub fn symbols(self) -> [&'static str; 6] {
+        match self {
+            BorderType::Plain => ["┌", "┐", "└", "┘", "─", "│"],
+            BorderType::Rounded => ["╭", "╮", "╰", "╯", "─", "│"],
+            BorderType::Double => ["╔", "╗", "╚", "╝", "═", "║"],
+            BorderType::Thick => ["┏", "┓", "┗", "┛", "━", "┃"],
+            BorderType::Ascii => ["+", "+", "+", "+", "-", "|"],
+        }
+    }
+}
+
+/// * Empty cells between the border and the content.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Padding {
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+
+impl Padding {
+    pub fn new(left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// * Same padding on every side.
+    pub fn uniform(n: u16) -> Self {
+        Self::new(n, n, n, n)
+    }
+
+    /// * Padding on the left and right sides.
+    pub fn horizontal(n: u16) -> Self {
+        Self::new(n, n, 0, 0)
+    }
+
+    /// * Padding on the top and bottom sides.
+    pub fn vertical(n: u16) -> Self {
+        Self::new(0, 0, n, n)
+    }
+}
+
+/// * Border, title and padding around a Widget.
+/// * Give it to a Widget with `.block(..)`, or wrap any Widget with `.child(..)`.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(12, 4)).unwrap();
+/// let mut root = Block::bordered()
+///     .border_type(BorderType::Rounded)
+///     .title("Logs")
+///     .title_align(Alignment::Center)
+///     .child(lable("ok"));
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec!["╭───Logs───╮", "│ ok       │", "│          │", "╰──────────╯"]
+/// );
+/// ```
+pub struct Block {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Sides with a border.
+    pub borders: Borders,
+
+    /// Border characters.
+    pub border_type: BorderType,
+
+    /// Border style, drawn over the block style.
+    pub border_style: Style,
+
+    /// Style of the whole area.
+    pub style: Style,
+
+    /// Title, on the top edge.
+    pub title: Option<Span>,
+
+    /// Title Alignment
+    pub title_alignment: Alignment,
+
+    /// Space between the border and the content.
+    pub padding: Padding,
+
+    /// Wrapped Widget.
+    pub child: Option<Box<dyn Widget>>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Block {
+    /// * Construct new block without borders.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            borders: Borders::NONE,
+            border_type: BorderType::Plain,
+            border_style: Style::default(),
+            style: Style::default(),
+            title: None,
+            title_alignment: Alignment::Left,
+            padding: Padding::default(),
+            child: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// * Construct new block with borders on every side.
+    pub fn bordered() -> Self {
+        Self::new().borders(Borders::ALL)
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Set the sides with a border.
+    pub fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Set the border characters.
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// * Set the style of the border, drawn over the block style.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// * Set the style of the whole area.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the title, drawn on the top edge.
+    pub fn title(mut self, title: impl Into<Span>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set alignment for the title.
+    pub fn title_align(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Set space between the border and the content.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the wrapped Widget.
+    pub fn child(mut self, widget: impl AsWidget) -> Self {
+        self.child = Some(widget.as_widget());
+        self
+    }
+
+    /// * Check if the top row is used, by the border or the title.
+    fn has_top(&self) -> bool {
+        self.borders.contains(Borders::TOP) || self.title.is_some()
+    }
+
+    /// * Columns used by borders and padding.
+    pub fn horizontal_space(&self) -> f32 {
+        let left = self.borders.contains(Borders::LEFT) as u16 + self.padding.left;
+        let right = self.borders.contains(Borders::RIGHT) as u16 + self.padding.right;
+        (left + right) as f32
+    }
+
+    /// * Rows used by borders, title and padding.
+    pub fn vertical_space(&self) -> f32 {
+        let top = self.has_top() as u16 + self.padding.top;
+        let bottom = self.borders.contains(Borders::BOTTOM) as u16 + self.padding.bottom;
+        (top + bottom) as f32
+    }
+
+    /// * Area left for the content inside `rect`.
+    pub fn inner(&self, rect: Rect) -> Rect {
+        let left = (self.borders.contains(Borders::LEFT) as u16 + self.padding.left) as f32;
+        let top = (self.has_top() as u16 + self.padding.top) as f32;
+        Rect::new(
+            rect.x + left,
+            rect.y + top,
+            (rect.w - self.horizontal_space()).max(0.0),
+            (rect.h - self.vertical_space()).max(0.0),
+        )
+    }
+
+    /// * Draw the block (not the child) into `rect`.
+    pub fn draw(&self, rect: Rect, buf: &mut Buffer) {
+        self.draw_with(rect, buf, self.border_type, self.border_style);
+    }
+
+    /// * Draw the block with other border characters and style,
+    ///   for Widgets that change their border on focus.
+    pub fn draw_with(
+        &self,
+        rect: Rect,
+        buf: &mut Buffer,
+        border_type: BorderType,
+        border_style: Style,
+    ) {
+        buf.set_style(rect, self.style);
+        let (w, h) = (rect.w.max(0.0) as usize, rect.h.max(0.0) as usize);
+        if w == 0 || h == 0 {
+            return;
+        }
+
+        let [tl, tr, bl, br, hor, ver] = border_type.symbols();
+        let (left, right) = (
+            self.borders.contains(Borders::LEFT),
+            self.borders.contains(Borders::RIGHT),
+        );
+        let edge = |l: &str, r: &str| -> String {
+            (0..w)
+                .map(|i| match i {
+                    0 if left => l,
+                    i if i == w - 1 && right => r,
+                    _ => hor,
+                })
+                .collect()
+        };
+
+        let bottom = rect.y + h as f32 - 1.0;
+        if self.borders.contains(Borders::TOP) {
+            buf.draw_styled((rect.x, rect.y), &edge(tl, tr), border_style);
+        }
+        if self.borders.contains(Borders::BOTTOM) && (h > 1 || !self.has_top()) {
+            buf.draw_styled((rect.x, bottom), &edge(bl, br), border_style);
+        }
+        let first = rect.y as u16 + self.borders.contains(Borders::TOP) as u16;
+        let last = bottom as u16 + !self.borders.contains(Borders::BOTTOM) as u16;
+        for y in first..last {
+            if left {
+                buf.draw_styled((rect.x, y as f32), ver, border_style);
+            }
+            if right {
+                buf.draw_styled((rect.x + w as f32 - 1.0, y as f32), ver, border_style);
+            }
+        }
+
+        if let Some(title) = &self.title {
+            // between the corners.
+            let (x, available) = match w {
+                0..=2 => (rect.x, w),
+                _ => (rect.x + 1.0, w - 2),
+            };
+            let title = truncate_spans(std::slice::from_ref(title), available);
+            let offset = self.title_alignment.offset(available, spans_width(&title));
+            buf.draw_spans((x + offset as f32, rect.y), &title);
+        }
+    }
+}
+
+impl Default for Block {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for Block {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        self.draw(rect, buf);
+        let inner = self.inner(rect);
+        if let Some(child) = &mut self.child {
+            render_child(child.as_mut(), inner, buf);
+        }
+
+        // required.
+        self.shape = rect;
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        let child = self.child.as_ref()?;
+        let height = child.preferred_height((width - self.horizontal_space()).max(0.0))?;
+        Some(height + self.vertical_space())
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match &mut self.child {
+            Some(child) => child.handle_event(event, ctx),
+            None => false,
+        }
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        self.child.as_slice()
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        self.child.as_mut_slice()
+    }
+}
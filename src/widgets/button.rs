@@ -1,5 +1,20 @@
-use super::{AsWidget, Rect, Widget};
-use crate::{Alignment, Console};
+use super::{Id, IntoId, LookupError, Rect, Widget};
+use crate::{text_width, truncate, Alignment, Block, BorderType, Buffer, Context, Style};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::{
+    any::{type_name, Any},
+    cell::RefCell,
+    rc::Rc,
+};
+
+/// * Callback triggered by `on_click`.
+pub type Callback = Rc<dyn Fn(&mut dyn Widget)>;
+
+/// * Callback triggered by `on_click_state`, receives the application state.
+pub type StateCallback = Rc<RefCell<dyn FnMut(&mut dyn Any) -> Result<(), LookupError>>>;
+
+/// * Message sent by `on_click_msg`, for an `App`.
+pub type Message = Rc<dyn Fn() -> Box<dyn Any>>;
 
 pub struct Button {
     /// Widget id
@@ -11,17 +26,32 @@ pub struct Button {
     /// Text Alignment
     alignment: Alignment,
 
-    /// Wrap around.
-    wrap: bool,
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Keyboard focus.
+    focused: bool,
 
-    /// Related id.
-    pub rid: Option<usize>,
+    /// Widget style.
+    style: Style,
+
+    /// Border style.
+    border_style: Style,
+
+    /// Drawn over the other styles when focused.
+    focus_style: Style,
+
+    /// Related ids, the callback runs on each of them.
+    pub rids: Vec<Id>,
 
     /// Callback function.
-    pub callback: Option<Box<dyn Fn(&mut Widget) -> &mut Widget>>,
+    pub callback: Option<Callback>,
+
+    /// Callback changing the application state.
+    pub state_callback: Option<StateCallback>,
 
-    /// Console controler
-    pub console: Console,
+    /// Message sent when clicked.
+    pub message: Option<Message>,
 
     /// Widget shape.
     pub shape: Rect,
@@ -32,57 +62,105 @@ impl Button {
     pub fn new(text: impl Into<String>) -> Self {
         Self {
             id: None,
-            rid: None,
+            rids: Vec::new(),
             callback: None,
+            state_callback: None,
+            message: None,
             text: text.into(),
-            wrap: true,
+            block: Some(Block::bordered()),
+            focused: false,
+            style: Style::default(),
+            border_style: Style::default(),
+            focus_style: Style::default().bold(),
             alignment: Alignment::Center,
-            console: Console::new(),
             shape: Rect::default(),
         }
     }
 
     /// * Storing the callback and taking action after getting clicked.
+    /// * It runs on every related Widget, or on the button itself without related ids.
     ///
     /// Example:
     ///
     /// ```no_run
-    ///     widgets![
+    /// use simple_tui::{functions::*, widgets};
+    ///
+    /// vbox(widgets![
     ///         lable("before")
     ///             .set_id(1337),
     ///         button("click me!")
     ///             .set_rid(1337)     // set related id to 1337 => `lable("before")`
-    ///             .on_click(|w: &mut Widget>| {
-    ///                 if let Widget::Lable(l) = w { l.text = "after".into() };
-    ///                 w
-    ///              }),
-    ///         ]
+    ///             .on_click(|w: &mut dyn Widget| {
+    ///                 if let Some(l) = w.downcast_mut::<Lable>() { l.text = "after".into() };
+    ///              })
+    ///         ]);
     /// ```
-    pub fn on_click(mut self, f: impl Fn(&mut Widget) -> &mut Widget + 'static) -> Self {
-        self.callback = Some(Box::new(f));
+    pub fn on_click(mut self, f: impl Fn(&mut dyn Widget) + 'static) -> Self {
+        self.callback = Some(Rc::new(f));
         self
     }
 
-    /// Widget id
-    pub fn id(&self) -> Option<usize> {
-        self.id
+    /// * Storing the callback changing the application state after getting clicked,
+    ///   no related id needed. See `Store`.
+    /// * Reported as `LookupError::WrongState` when the state isn't an `S`.
+    pub fn on_click_state<S: 'static>(mut self, mut f: impl FnMut(&mut S) + 'static) -> Self {
+        self.state_callback = Some(Rc::new(RefCell::new(move |state: &mut dyn Any| {
+            let state = state.downcast_mut::<S>();
+            state
+                .map(&mut f)
+                .ok_or(LookupError::WrongState(type_name::<S>()))
+        })));
+        self
+    }
+
+    /// * Send `msg` to the `App` after getting clicked, no related id needed. See `App`.
+    /// * `msg` must be an `App::Msg`: pass `Msg::Add(1)`, `Msg::Add` alone is a function.
+    pub fn on_click_msg<M: Clone + 'static>(mut self, msg: M) -> Self {
+        self.message = Some(Rc::new(move || Box::new(msg.clone())));
+        self
     }
 
     /// Set Widget id
-    pub fn set_id(mut self, id: usize) -> Self {
-        self.id = Some(id);
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
         self
     }
 
-    /// * Set related id for any Widget inside the same widgets array. \
-    pub fn set_rid(mut self, rid: usize) -> Self {
-        self.rid = Some(rid);
+    /// * Set related id for any Widget in the tree. \
+    /// * Replaces the related ids set before.
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rids = vec![rid.into_id()];
+        self
+    }
+
+    /// * Add a related id, the callback runs on every related Widget in order.
+    pub fn add_rid(mut self, rid: impl IntoId) -> Self {
+        self.rids.push(rid.into_id());
+        self
+    }
+
+    /// * Set all the related ids, the callback runs on every related Widget in order.
+    pub fn set_rids<I: IntoId>(mut self, rids: Vec<I>) -> Self {
+        self.rids = rids.into_iter().map(IntoId::into_id).collect();
         self
     }
 
     /// Check if Widget's wrap around.
     pub fn is_wrap(&self) -> bool {
-        self.wrap
+        self.block.is_some()
+    }
+
+    /// Don't wrap around.
+    pub fn no_wrap(mut self) -> Self {
+        self.block = None;
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    /// * The border is drawn with `BorderType::Double` when focused.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
     }
 
     /// Set alignment for the text.
@@ -91,47 +169,132 @@ impl Button {
         self
     }
 
-    /// Render text with consideration the alignment
-    fn render_text(&mut self, rect: &Rect) {
-        match self.alignment {
-            Alignment::Left => self.console.draw((rect.x + 1.0, rect.y), &self.text),
-            Alignment::Center => self.console.draw(
-                (
-                    rect.x + 1.0 + ((rect.w * 0.5) - (self.text.len() as f32 * 0.5)),
-                    rect.y,
-                ),
-                &self.text,
-            ),
-            Alignment::Right => self.console.draw(
-                (rect.x - 1.0 + (rect.w - self.text.len() as f32), rect.y),
-                &self.text,
-            ),
+    /// * Set the style of the whole widget, text included.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style of the border, drawn over the widget and block styles.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// * Set the style used while the button has the keyboard focus, bold by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// * Queue the callback on the related Widgets (or run it on the button),
+    ///   the change of the application state and the message.
+    fn press(&mut self, ctx: &mut Context) {
+        if let Some(msg) = &self.message {
+            ctx.send_any(msg());
+        }
+        if let Some(f) = &self.state_callback {
+            let f = Rc::clone(f);
+            ctx.update_any(move |state| (f.borrow_mut())(state));
         }
+        if let Some(f) = &self.callback {
+            let f = Rc::clone(f);
+            if self.rids.is_empty() {
+                return f(self);
+            }
+            for rid in &self.rids {
+                let f = Rc::clone(&f);
+                ctx.trigger(rid.clone(), move |w| f(w));
+            }
+        }
+    }
+
+    /// Render text with consideration the alignment
+    fn render_text(&mut self, rect: &Rect, buf: &mut Buffer) {
+        let available = rect.w.max(0.0) as usize;
+        let text = truncate(&self.text, available);
+        let x = self.alignment.offset(available, text_width(&text));
+        buf.draw((rect.x + x as f32, rect.y), &text);
     }
+}
+
+impl Widget for Button {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        let height = self
+            .block
+            .as_ref()
+            .map_or(3.0, |b| b.vertical_space() + 1.0);
+        self.shape = Rect::new(rect.x, rect.y, rect.w, rect.h.min(height));
+        buf.set_style(self.shape, self.style);
+        if self.focused {
+            buf.set_style(self.shape, self.focus_style);
+        }
 
-    /// Render the widget on console.
-    pub fn render(&mut self, rect: Rect) {
-        if !self.wrap {
-            self.console.draw((rect.x, rect.y), &self.text);
+        let Some(block) = &self.block else {
+            // middle row of the shape.
+            let y = rect.y + ((self.shape.h - 1.0) * 0.5).floor();
+            self.render_text(&Rect::new(rect.x, y, rect.w, 1.0), buf);
             return;
+        };
+
+        // wrap around, double line when focused.
+        let border_type = match self.focused {
+            true => BorderType::Double,
+            false => block.border_type,
+        };
+        let border_style = block.border_style.patch(self.border_style);
+        block.draw_with(self.shape, buf, border_type, border_style);
+        let content = block.inner(self.shape);
+        self.render_text(&content, buf);
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        Some(
+            self.block
+                .as_ref()
+                .map_or(3.0, |b| b.vertical_space() + 1.0),
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if self.shape.contains(*column, *row) => self.press(ctx),
+            Event::Key(key)
+                if self.focused
+                    && key.kind != KeyEventKind::Release
+                    && matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) =>
+            {
+                self.press(ctx)
+            }
+            _ => return false,
         }
+        true
+    }
 
-        // wrap around
-        let top = "┌".to_string() + &"─".repeat(rect.w as usize - 2) + "┐";
-        let donw = "└".to_string() + &"─".repeat(rect.w as usize - 2) + "┘";
-        self.console.draw((rect.x, rect.y - 1.0), &top);
-        self.console.draw((rect.x, rect.y), "│");
-        self.render_text(&rect);
-        self.console.draw((rect.x + rect.w - 1.0, rect.y), "│");
-        self.console.draw((rect.x, rect.y + 1.0), &donw);
+    fn focusable(&self) -> bool {
+        true
+    }
 
-        // required.
-        self.shape = rect;
+    fn is_focused(&self) -> bool {
+        self.focused
     }
-}
 
-impl AsWidget for Button {
-    fn as_widget(self) -> Option<Widget> {
-        Some(Widget::Button(self))
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
     }
 }
@@ -0,0 +1,349 @@
+use super::{IntoId, Rect, Widget};
+use crate::{Block, Buffer, Color, Style};
+
+/// * Figure drawn on a `Canvas`, in canvas coordinates (`y` goes up).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    /// Single dots.
+    Points {
+        coords: Vec<(f64, f64)>,
+        color: Color,
+    },
+
+    /// Segment from `(x1, y1)` to `(x2, y2)`.
+    Line {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: Color,
+    },
+
+    /// Outline, `(x, y)` is the bottom left corner.
+    Rectangle {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Color,
+    },
+
+    /// Outline around `(x, y)`.
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        color: Color,
+    },
+}
+
+/// Braille dot bits, as `[column][row]`.
+const DOTS: [[u16; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// * Grid of Braille cells, 2x4 dots each, dots counted from the top left.
+pub(super) struct BrailleGrid {
+    width: usize,
+    height: usize,
+    dots: Vec<u16>,
+    colors: Vec<Option<Color>>,
+}
+
+impl BrailleGrid {
+    /// * Construct new grid of `width` x `height` cells.
+    pub(super) fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            dots: vec![0; width * height],
+            colors: vec![None; width * height],
+        }
+    }
+
+    /// * Set the dot at `(x, y)`, ignored outside the grid.
+    fn set(&mut self, x: f64, y: f64, color: Color) {
+        let (x, y) = (x.round(), y.round());
+        if x < 0.0 || y < 0.0 || x >= (self.width * 2) as f64 || y >= (self.height * 4) as f64 {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let i = y / 4 * self.width + x / 2;
+        self.dots[i] |= DOTS[x % 2][y % 4];
+        self.colors[i] = Some(color);
+    }
+
+    /// * Set the dots between two points.
+    fn line(&mut self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), color: Color) {
+        let steps = (x2 - x1).abs().max((y2 - y1).abs()).ceil().min(1e4) as usize;
+        for i in 0..=steps {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                i as f64 / steps as f64
+            };
+            self.set(x1 + (x2 - x1) * t, y1 + (y2 - y1) * t, color);
+        }
+    }
+
+    /// * Draw `shape`, mapping `x_bounds` and `y_bounds` onto the whole grid.
+    pub(super) fn paint(&mut self, shape: &Shape, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        let (dots_w, dots_h) = (
+            (self.width * 2) as f64 - 1.0,
+            (self.height * 4) as f64 - 1.0,
+        );
+        let (span_x, span_y) = (x_bounds[1] - x_bounds[0], y_bounds[1] - y_bounds[0]);
+        if span_x <= 0.0 || span_y <= 0.0 {
+            return;
+        }
+        let map = |x: f64, y: f64| -> (f64, f64) {
+            (
+                (x - x_bounds[0]) / span_x * dots_w,
+                (y_bounds[1] - y) / span_y * dots_h,
+            )
+        };
+
+        match *shape {
+            Shape::Points { ref coords, color } => {
+                for &(x, y) in coords {
+                    let (x, y) = map(x, y);
+                    self.set(x, y, color);
+                }
+            }
+            Shape::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+            } => self.line(map(x1, y1), map(x2, y2), color),
+            Shape::Rectangle {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => {
+                let corners = [
+                    map(x, y),
+                    map(x + width, y),
+                    map(x + width, y + height),
+                    map(x, y + height),
+                ];
+                for i in 0..4 {
+                    self.line(corners[i], corners[(i + 1) % 4], color);
+                }
+            }
+            Shape::Circle {
+                x,
+                y,
+                radius,
+                color,
+            } => {
+                let (rx, ry) = (radius / span_x * dots_w, radius / span_y * dots_h);
+                let steps =
+                    ((rx.max(ry) * std::f64::consts::TAU).ceil() as usize).clamp(16, 1e4 as usize);
+                let (cx, cy) = map(x, y);
+                for i in 0..steps {
+                    let angle = i as f64 / steps as f64 * std::f64::consts::TAU;
+                    self.set(cx + rx * angle.cos(), cy + ry * angle.sin(), color);
+                }
+            }
+        }
+    }
+
+    /// * Draw the cells with at least a dot, the top left one at `(x, y)`.
+    pub(super) fn draw(&self, x: f32, y: f32, buf: &mut Buffer) {
+        for (i, dots) in self.dots.iter().enumerate() {
+            if *dots == 0 {
+                continue;
+            }
+            let symbol = char::from_u32(0x2800 + *dots as u32).unwrap_or(' ');
+            let pos = (x + (i % self.width) as f32, y + (i / self.width) as f32);
+            let style = Style::default();
+            let style = match self.colors[i] {
+                Some(color) => style.fg(color),
+                None => style,
+            };
+            buf.draw_styled(pos, &symbol.to_string(), style);
+        }
+    }
+}
+
+/// * Area to draw points, lines, rectangles and circles with Braille characters,
+///   2x4 dots per cell.
+/// * Shapes use canvas coordinates, `y` going up, mapped from the bounds onto the whole area.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+/// let mut plot = canvas()
+///     .x_bounds(0.0, 7.0)
+///     .y_bounds(0.0, 3.0)
+///     .line((0.0, 0.0), (7.0, 0.0), Color::Reset);
+/// terminal.draw(|rect, buf| plot.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["⣀⣀⣀⣀"]);
+/// ```
+pub struct Canvas {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Shapes, drawn in order.
+    pub shapes: Vec<Shape>,
+
+    /// Canvas `x` at the left and right edges.
+    x_bounds: [f64; 2],
+
+    /// Canvas `y` at the bottom and top edges.
+    y_bounds: [f64; 2],
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Canvas {
+    /// * Construct new empty canvas, from `0.0` to `1.0` on both axes.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            shapes: Vec::new(),
+            x_bounds: [0.0, 1.0],
+            y_bounds: [0.0, 1.0],
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Set canvas `x` at the left and right edges.
+    pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+        self.x_bounds = [min, max];
+        self
+    }
+
+    /// Set canvas `y` at the bottom and top edges.
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = [min, max];
+        self
+    }
+
+    /// * Add a shape, drawn over the previous ones.
+    pub fn paint(mut self, shape: Shape) -> Self {
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Add single dots.
+    pub fn points(self, coords: Vec<(f64, f64)>, color: Color) -> Self {
+        self.paint(Shape::Points { coords, color })
+    }
+
+    /// Add a segment.
+    pub fn line(self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), color: Color) -> Self {
+        self.paint(Shape::Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            color,
+        })
+    }
+
+    /// * Add a rectangle outline, `(x, y)` is its bottom left corner.
+    pub fn rectangle(self, (x, y): (f64, f64), width: f64, height: f64, color: Color) -> Self {
+        self.paint(Shape::Rectangle {
+            x,
+            y,
+            width,
+            height,
+            color,
+        })
+    }
+
+    /// Add a circle outline.
+    pub fn circle(self, (x, y): (f64, f64), radius: f64, color: Color) -> Self {
+        self.paint(Shape::Circle {
+            x,
+            y,
+            radius,
+            color,
+        })
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Remove every shape.
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+}
+
+impl Widget for Canvas {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(rect, buf);
+                block.inner(rect)
+            }
+            None => rect,
+        };
+        let (width, height) = (content.w.max(0.0) as usize, content.h.max(0.0) as usize);
+        if width == 0 || height == 0 {
+            return;
+        }
+        let mut grid = BrailleGrid::new(width, height);
+        for shape in &self.shapes {
+            grid.paint(shape, self.x_bounds, self.y_bounds);
+        }
+        grid.draw(content.x, content.y, buf);
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
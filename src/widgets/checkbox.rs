@@ -0,0 +1,232 @@
+use super::{Id, IntoId, Rect, Widget};
+use crate::{text_width, truncate, Buffer, Context, Style, VAlignment};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::{any::Any, rc::Rc};
+
+/// * Callback triggered by `on_change`, receives the new checked state.
+pub type CheckCallback = Rc<dyn Fn(&mut dyn Widget, bool)>;
+
+/// * Message sent by `on_change_msg`, made from the new checked state.
+pub type CheckMessage = Rc<dyn Fn(bool) -> Box<dyn Any>>;
+
+/// * Box that is checked or not, followed by its label.
+/// * A click or `Space` (when focused) flips it.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+/// let mut cb = checkbox("Verbose").checked(true);
+/// terminal.draw(|rect, buf| cb.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["[x] Verbose "]);
+/// ```
+pub struct Checkbox {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Text after the box.
+    pub text: String,
+
+    /// Checked state.
+    checked: bool,
+
+    /// Box when checked and unchecked.
+    symbols: (String, String),
+
+    /// Style of the box when checked, drawn over the widget style.
+    checked_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Drawn over the other styles when focused.
+    focus_style: Style,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the state changed.
+    pub on_change: Option<CheckCallback>,
+
+    /// Message sent after the state changed.
+    pub change_msg: Option<CheckMessage>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Checkbox {
+    /// * Construct new unchecked checkbox.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            text: text.into(),
+            checked: false,
+            symbols: ("[x]".into(), "[ ]".into()),
+            checked_style: Style::default(),
+            style: Style::default(),
+            focus_style: Style::default().reversed(),
+            focused: false,
+            rid: None,
+            on_change: None,
+            change_msg: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Set the initial state.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// * Set the box drawn when checked and unchecked, `[x]` and `[ ]` by default.
+    pub fn symbols(mut self, checked: impl Into<String>, unchecked: impl Into<String>) -> Self {
+        self.symbols = (checked.into(), unchecked.into());
+        self
+    }
+
+    /// * Set the style of the box when checked.
+    pub fn checked_style(mut self, style: Style) -> Self {
+        self.checked_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style used while the widget has the keyboard focus, reversed by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// * Storing the callback, triggered with the new state.
+    pub fn on_change(mut self, f: impl Fn(&mut dyn Widget, bool) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// * Send the message made by `f` from the new state to the `App`. See `App`.
+    pub fn on_change_msg<M: 'static>(mut self, f: impl Fn(bool) -> M + 'static) -> Self {
+        self.change_msg = Some(Rc::new(move |checked| Box::new(f(checked))));
+        self
+    }
+
+    /// Checked state.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Set the state, without calling `on_change`.
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// * Flip the state, queue `on_change` on the related Widget and send the message.
+    fn toggle(&mut self, ctx: &mut Context) {
+        self.checked = !self.checked;
+        if let Some(msg) = &self.change_msg {
+            ctx.send_any(msg(self.checked));
+        }
+        if let Some(f) = &self.on_change {
+            let (f, checked) = (Rc::clone(f), self.checked);
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, checked)),
+                None => f(self, checked),
+            }
+        }
+    }
+}
+
+impl Widget for Checkbox {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required, middle row.
+        let y = rect.y + VAlignment::Middle.offset(rect.h.max(0.0) as usize, 1) as f32;
+        self.shape = Rect::new(rect.x, y, rect.w, rect.h.min(1.0));
+        buf.set_style(self.shape, self.style);
+
+        let symbol = match self.checked {
+            true => &self.symbols.0,
+            false => &self.symbols.1,
+        };
+        let text = symbol.clone() + " " + &self.text;
+        buf.draw((rect.x, y), &truncate(&text, rect.w.max(0.0) as usize));
+        if self.checked {
+            let width = text_width(symbol) as f32;
+            buf.set_style(
+                Rect::new(rect.x, y, width.min(rect.w), 1.0),
+                self.checked_style,
+            );
+        }
+        if self.focused {
+            buf.set_style(self.shape, self.focus_style);
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        Some(1.0)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if self.shape.contains(*column, *row) => self.toggle(ctx),
+            Event::Key(key)
+                if self.focused
+                    && key.kind != KeyEventKind::Release
+                    && key.code == KeyCode::Char(' ') =>
+            {
+                self.toggle(ctx)
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
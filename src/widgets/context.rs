@@ -0,0 +1,269 @@
+use super::{find_by_id_mut, focus_next, walk_mut, Id, IntoId, Widget};
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use std::{
+    any::{type_name, Any},
+    error::Error,
+    fmt,
+};
+
+/// * Callback waiting for the Widget with its related id.
+type Pending = (Id, Box<dyn FnOnce(&mut dyn Widget)>);
+
+/// * Change waiting for the application state.
+type Update = Box<dyn FnOnce(&mut dyn Any) -> Result<(), LookupError>>;
+
+/// * Why a callback couldn't run.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut root = vbox(widgets![lable("").set_id("status")]);
+/// let mut ctx = Context::new();
+/// ctx.trigger("sttus", |_: &mut dyn Widget| {});
+/// let err = ctx.apply(&mut root).unwrap_err();
+///
+/// assert_eq!(err, LookupError::NotFound("sttus".into_id()));
+/// assert_eq!(err.to_string(), "no Widget with id \"sttus\" in the tree");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LookupError {
+    /// No Widget in the tree has this id.
+    NotFound(Id),
+
+    /// The application state isn't of this type (`ctx.update::<S>(..)`, `on_click_state`).
+    WrongState(&'static str),
+
+    /// The message sent to the `App` isn't of this type, its `App::Msg` (`ctx.send(..)`, `on_click_msg`).
+    WrongMessage(&'static str),
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LookupError::NotFound(id) => write!(f, "no Widget with id {id} in the tree"),
+            LookupError::WrongState(name) => write!(f, "the application state isn't a `{name}`"),
+            LookupError::WrongMessage(name) => write!(f, "the message sent isn't a `{name}`"),
+        }
+    }
+}
+
+impl Error for LookupError {}
+
+/// * Collects what Widgets want to do while an event travels through the tree.
+/// * Callbacks run after the event is handled, when the whole tree can be searched.
+#[derive(Default)]
+pub struct Context {
+    /// Callbacks waiting for their Widget.
+    pending: Vec<Pending>,
+
+    /// Changes waiting for the application state.
+    updates: Vec<Update>,
+
+    /// Messages for the `App`, in the order they were sent.
+    messages: Vec<Box<dyn Any>>,
+
+    /// The top layer of the nearest `Overlay` asked to be closed.
+    close: bool,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// * Run `f` on the Widget with id `rid` once the event is handled.
+    pub fn trigger(&mut self, rid: impl IntoId, f: impl FnOnce(&mut dyn Widget) + 'static) {
+        self.pending.push((rid.into_id(), Box::new(f)));
+    }
+
+    /// * Change the application state (the state of a `Store`) once the event is handled.
+    /// * Reported as `LookupError::WrongState` when the state isn't an `S`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::functions::*;
+    ///
+    /// let mut store = Store::new(0_i64);
+    /// let mut ctx = Context::new();
+    /// ctx.update(|n: &mut i64| *n += 1);
+    /// ctx.update(|n: &mut i32| *n += 1); // not the state type.
+    ///
+    /// let result = store.apply(&mut lable(""), ctx);
+    /// assert_eq!(result, Err(LookupError::WrongState("i32")));
+    /// assert_eq!(store.state, 1);
+    /// ```
+    pub fn update<S: 'static>(&mut self, f: impl FnOnce(&mut S) + 'static) {
+        self.update_any(move |state| {
+            let state = state.downcast_mut::<S>();
+            state
+                .map(f)
+                .ok_or(LookupError::WrongState(type_name::<S>()))
+        });
+    }
+
+    /// * Same as `update`, for callbacks that downcast the state themselves
+    ///   and report a state of the wrong type.
+    pub fn update_any(
+        &mut self,
+        f: impl FnOnce(&mut dyn Any) -> Result<(), LookupError> + 'static,
+    ) {
+        self.updates.push(Box::new(f));
+    }
+
+    /// * Send `msg` to the `App::update` of a `Program` once the event is handled.
+    /// * Dropped and reported as `LookupError::WrongMessage` when it isn't an `App::Msg`,
+    ///   ignored outside of a `Program`.
+    pub fn send<M: 'static>(&mut self, msg: M) {
+        self.send_any(Box::new(msg));
+    }
+
+    /// * Same as `send`, for messages already boxed.
+    pub fn send_any(&mut self, msg: Box<dyn Any>) {
+        self.messages.push(msg);
+    }
+
+    /// * Close the top layer of the `Overlay` the event went through, once the event is handled.
+    pub fn close_overlay(&mut self) {
+        self.close = true;
+    }
+
+    /// * Check if closing the top layer was asked, and forget about it.
+    pub(super) fn take_close(&mut self) -> bool {
+        std::mem::take(&mut self.close)
+    }
+
+    /// * Take the messages sent so far, for the `App`.
+    pub fn take_messages(&mut self) -> Vec<Box<dyn Any>> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// * Run the waiting callbacks on the Widgets under `root`.
+    /// * Callbacks whose Widget isn't found are dropped, and changes to a state of another type
+    ///   fail; the first error is returned once everything else ran.
+    pub fn apply(self, root: &mut dyn Widget) -> Result<(), LookupError> {
+        self.apply_with(root, &mut ())
+    }
+
+    /// * Change `state`, then run the waiting callbacks on the Widgets under `root`.
+    pub fn apply_with(
+        mut self,
+        root: &mut dyn Widget,
+        state: &mut dyn Any,
+    ) -> Result<(), LookupError> {
+        let mut error = None;
+        for f in std::mem::take(&mut self.updates) {
+            if let Err(e) = f(state) {
+                error.get_or_insert(e);
+            }
+        }
+        self.resolve(root);
+        if let Some((rid, _)) = self.pending.first() {
+            error.get_or_insert(LookupError::NotFound(rid.clone()));
+        }
+        error.map_or(Ok(()), Err)
+    }
+
+    /// * Run the waiting callbacks whose Widget is under `root`, keep the others waiting.
+    fn resolve(&mut self, root: &mut dyn Widget) {
+        let pending = std::mem::take(&mut self.pending);
+        for (rid, f) in pending {
+            match find_by_id_mut(root, rid.value()) {
+                Some(w) => f(w),
+                None => self.pending.push((rid, f)),
+            }
+        }
+    }
+
+    /// * Keep waiting for the callbacks, changes, messages and close request of `other`.
+    pub(super) fn append(&mut self, other: Context) {
+        self.pending.extend(other.pending);
+        self.updates.extend(other.updates);
+        self.messages.extend(other.messages);
+        self.close |= other.close;
+    }
+}
+
+/// * Send one input event through the tree under `root`.
+/// * `Tab`/`Shift-Tab` move the focus, everything else goes to the Widgets,
+///   then the callbacks they triggered run.
+pub fn dispatch(root: &mut dyn Widget, event: &Event) -> Result<(), LookupError> {
+    dispatch_with(root, event, &mut ())
+}
+
+/// * Same as `dispatch`, the changes queued with `ctx.update(..)` are made to `state`
+///   before the callbacks run.
+pub fn dispatch_with(
+    root: &mut dyn Widget,
+    event: &Event,
+    state: &mut dyn Any,
+) -> Result<(), LookupError> {
+    match route(root, event) {
+        Some((ctx, _)) => ctx.apply_with(root, state),
+        None => Ok(()),
+    }
+}
+
+/// * Give one input event to the Widgets under `root`, or move the focus for `Tab`/`Shift-Tab`.
+/// * Return what the Widgets want to do and whether one of them used the event,
+///   `None` when the event didn't reach them.
+pub fn route(root: &mut dyn Widget, event: &Event) -> Option<(Context, bool)> {
+    if let Event::Key(key) = event {
+        match key.code {
+            _ if key.kind == KeyEventKind::Release => return None,
+            KeyCode::Tab | KeyCode::BackTab => {
+                focus_next(root, key.code == KeyCode::BackTab);
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut ctx = Context::new();
+    let used = root.handle_event(event, &mut ctx);
+    Some((ctx, used))
+}
+
+/// * Give one tick to every Widget under `root` (hidden pages and layers excluded).
+/// * Return what the Widgets want to do.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// struct Spinner {
+///     frame: usize,
+///     shape: Rect,
+/// }
+///
+/// impl Widget for Spinner {
+///     fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+///         buf.draw((rect.x, rect.y), ["|", "/", "-", "\\"][self.frame % 4]);
+///         self.shape = rect;
+///     }
+///
+///     fn shape(&self) -> &Rect {
+///         &self.shape
+///     }
+///
+///     fn on_tick(&mut self, _ctx: &mut Context) {
+///         self.frame += 1;
+///     }
+/// }
+///
+/// let mut root = hbox(widgets![Spinner { frame: 0, shape: Rect::default() }]);
+/// tick(&mut root).apply(&mut root).unwrap();
+/// tick(&mut root).apply(&mut root).unwrap();
+///
+/// let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+/// assert_eq!(terminal.backend().lines(), vec!["-"]);
+/// ```
+pub fn tick(root: &mut dyn Widget) -> Context {
+    let mut ctx = Context::new();
+    walk_mut(root, &mut |w| w.on_tick(&mut ctx));
+    ctx
+}
@@ -0,0 +1,259 @@
+use super::{Id, IntoId, ListCallback, Rect, Widget};
+use crate::{
+    text_width, truncate, wrap_spans, Alignment, Block, Buffer, Context, Span, Style, TextWrap,
+};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::rc::Rc;
+
+/// * Bordered box with a title, a message and a row of buttons, `OK` and `Cancel` by default.
+/// * Meant to be opened on an `Overlay`: `Left`/`Right` pick a button, `Enter`/`Space`
+///   or a click press it, then the dialog closes itself.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+/// let mut quit = dialog("Quit", "Are you sure?");
+/// terminal.draw(|rect, buf| quit.render(rect, buf)).unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec![
+///         "┌───────Quit───────┐",
+///         "│  Are you sure?   │",
+///         "│                  │",
+///         "│[ OK ] [ Cancel ] │",
+///         "└──────────────────┘",
+///     ]
+/// );
+/// ```
+pub struct Dialog {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Text above the buttons.
+    pub message: String,
+
+    /// Button labels, left to right.
+    pub buttons: Vec<String>,
+
+    /// Button pressed by `Enter`.
+    selected: usize,
+
+    /// Style of the selected button, drawn over the widget style.
+    highlight_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border and title.
+    block: Block,
+
+    /// Columns of each button, as `(x, width)`.
+    button_columns: Vec<(f32, f32)>,
+
+    /// Row of the buttons.
+    buttons_y: f32,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after a button is pressed.
+    pub on_close: Option<ListCallback>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Dialog {
+    /// * Construct new dialog with `OK` and `Cancel` buttons.
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            message: message.into(),
+            buttons: vec!["OK".into(), "Cancel".into()],
+            selected: 0,
+            highlight_style: Style::default().reversed(),
+            style: Style::default(),
+            block: Block::bordered()
+                .title(Span::raw(title.into()))
+                .title_align(Alignment::Center),
+            button_columns: Vec::new(),
+            buttons_y: 0.0,
+            rid: None,
+            on_close: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Set the button labels, left to right.
+    pub fn buttons<T: Into<String>>(mut self, buttons: Vec<T>) -> Self {
+        self.buttons = buttons.into_iter().map(Into::into).collect();
+        self.selected = 0;
+        self
+    }
+
+    /// Select the `index`th button.
+    pub fn select(mut self, index: usize) -> Self {
+        self.selected = index.min(self.buttons.len().saturating_sub(1));
+        self
+    }
+
+    /// * Set the style of the selected button, reversed by default.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Replace the border and title.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// * Storing the callback, triggered with the pressed button.
+    pub fn on_close(mut self, f: impl Fn(&mut dyn Widget, usize, &str) + 'static) -> Self {
+        self.on_close = Some(Rc::new(f));
+        self
+    }
+
+    /// Selected button index.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// * Press the `index`th button: queue `on_close` on the related Widget and close the dialog.
+    fn press(&mut self, index: usize, ctx: &mut Context) {
+        self.selected = index;
+        if let (Some(f), Some(button)) = (&self.on_close, self.buttons.get(index)) {
+            let (f, button) = (Rc::clone(f), button.clone());
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, index, &button)),
+                None => f(self, index, &button),
+            }
+        }
+        ctx.close_overlay();
+    }
+
+    /// Message rows when `width` columns wide.
+    fn message_lines(&self, width: usize) -> Vec<String> {
+        wrap_spans(&[Span::raw(self.message.as_str())], width, TextWrap::Word)
+            .iter()
+            .map(|line| {
+                let line: String = line.iter().map(|s| s.text.as_str()).collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+}
+
+impl Widget for Dialog {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+        self.block.draw(rect, buf);
+
+        let content = self.block.inner(rect);
+        let (width, height) = (content.w.max(0.0) as usize, content.h.max(0.0) as usize);
+        self.button_columns.clear();
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        // buttons on the last row, the message above.
+        for (row, line) in self
+            .message_lines(width)
+            .iter()
+            .take(height - 1)
+            .enumerate()
+        {
+            let x = content.x + Alignment::Center.offset(width, text_width(line)) as f32;
+            buf.draw((x, content.y + row as f32), line);
+        }
+
+        let labels: Vec<String> = self.buttons.iter().map(|b| format!("[ {b} ]")).collect();
+        let total = labels.iter().map(|l| text_width(l) + 1).sum::<usize>();
+        let mut x = content.x + Alignment::Center.offset(width, total.saturating_sub(1)) as f32;
+        let end = content.x + width as f32;
+        self.buttons_y = content.y + height as f32 - 1.0;
+        for (i, label) in labels.iter().enumerate() {
+            let label = truncate(label, (end - x).max(0.0) as usize);
+            let w = text_width(&label) as f32;
+            buf.draw((x, self.buttons_y), &label);
+            if i == self.selected {
+                buf.set_style(Rect::new(x, self.buttons_y, w, 1.0), self.highlight_style);
+            }
+            self.button_columns.push((x, w));
+            x += w + 1.0;
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        let width = (width - self.block.horizontal_space()).max(1.0) as usize;
+        let rows = self.message_lines(width).len() + 2;
+        Some(rows as f32 + self.block.vertical_space())
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if *row as f32 == self.buttons_y => {
+                let x = *column as f32;
+                let clicked = self
+                    .button_columns
+                    .iter()
+                    .position(|(start, width)| x >= *start && x < start + width);
+                match clicked {
+                    Some(i) => self.press(i, ctx),
+                    None => return false,
+                }
+            }
+            Event::Key(key) if key.kind != KeyEventKind::Release && !self.buttons.is_empty() => {
+                match key.code {
+                    KeyCode::Left => self.selected = self.selected.saturating_sub(1),
+                    KeyCode::Right => {
+                        self.selected = (self.selected + 1).min(self.buttons.len() - 1)
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ') => self.press(self.selected, ctx),
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
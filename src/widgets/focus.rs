@@ -0,0 +1,63 @@
+use super::{walk, walk_mut, Widget};
+
+/// * Move the focus to the next focusable Widget under `root`, or the previous one when `reverse`.
+/// * Widgets are visited in tree order (depth first, `Vbox`/`Hbox` order), wraps around at both ends.
+pub fn focus_next(root: &mut dyn Widget, reverse: bool) {
+    // First pass: count the focusable Widgets, find and clear the current one.
+    let (mut n, mut current) = (0, None);
+    walk_mut(root, &mut |w| {
+        if w.focusable() {
+            if w.is_focused() {
+                current = Some(n);
+                w.set_focus(false);
+            }
+            n += 1;
+        }
+    });
+    if n == 0 {
+        return;
+    }
+
+    let next = match (current, reverse) {
+        (None, false) => 0,
+        (None, true) => n - 1,
+        (Some(i), false) => (i + 1) % n,
+        (Some(i), true) => (i + n - 1) % n,
+    };
+
+    // Second pass: focus the next one.
+    let mut i = 0;
+    walk_mut(root, &mut |w| {
+        if w.focusable() {
+            if i == next {
+                w.set_focus(true);
+            }
+            i += 1;
+        }
+    });
+}
+
+/// * Position of the focused Widget among the focusable Widgets under `root`, in tree order.
+pub fn focus_index(root: &dyn Widget) -> Option<usize> {
+    let (mut n, mut current) = (0, None);
+    walk(root, &mut |w| {
+        if w.focusable() {
+            if w.is_focused() && current.is_none() {
+                current = Some(n);
+            }
+            n += 1;
+        }
+    });
+    current
+}
+
+/// * Focus the `index`th focusable Widget under `root`, in tree order, and no other.
+pub fn set_focus_index(root: &mut dyn Widget, index: usize) {
+    let mut i = 0;
+    walk_mut(root, &mut |w| {
+        if w.focusable() {
+            w.set_focus(i == index);
+            i += 1;
+        }
+    });
+}
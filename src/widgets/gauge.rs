@@ -0,0 +1,167 @@
+use super::{progress_bar::draw_bar, IntoId, Rect, Widget};
+use crate::{Block, Buffer, Style};
+
+/// * Progress filling the whole area, left to right with a precision of 1/8 cell,
+///   with the percentage (or a label) in the middle.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+/// let mut disk = gauge().ratio(0.5);
+/// terminal.draw(|rect, buf| disk.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["█████     ", "███50%    ", "█████     "]);
+/// ```
+pub struct Gauge {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Progress, from 0.0 to 1.0.
+    ratio: f64,
+
+    /// Text drawn instead of the percentage.
+    label: Option<String>,
+
+    /// Style of the filled part, drawn over the widget style.
+    filled_style: Style,
+
+    /// Style of the empty part, drawn over the widget style.
+    empty_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Gauge {
+    /// * Construct new empty gauge.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            ratio: 0.0,
+            label: None,
+            filled_style: Style::default(),
+            empty_style: Style::default(),
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Set the progress, kept between 0.0 and 1.0.
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    /// Set the text drawn instead of the percentage.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// * Set the style of the filled part.
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// * Set the style of the empty part.
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Progress, from 0.0 to 1.0.
+    pub fn get_ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Set the progress, kept between 0.0 and 1.0.
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = match ratio.is_nan() {
+            true => 0.0,
+            false => ratio.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Set the text drawn instead of the percentage.
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+}
+
+impl Widget for Gauge {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(rect, buf);
+                block.inner(rect)
+            }
+            None => rect,
+        };
+        let label = match &self.label {
+            Some(label) => label.clone(),
+            None => format!("{}%", (self.ratio * 100.0).round()),
+        };
+        draw_bar(
+            content,
+            self.ratio,
+            Some(&label),
+            self.filled_style,
+            self.empty_style,
+            buf,
+        );
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+}
+
+impl Default for Gauge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
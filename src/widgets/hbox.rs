@@ -1,221 +1,123 @@
-use crate::{inner_vbox_render, inner_vbox_trigger, AsWidget, Console, EventHandler};
-use crossterm::{
-    event::{
-        read, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
-    },
-    terminal::{disable_raw_mode, enable_raw_mode},
-};
-
-use super::{Rect, Widget};
+use super::{render_child, IntoId, Rect, Widget};
+use crate::{split, Block, Buffer, Constraint, Context, Direction};
+use crossterm::event::Event;
 
 pub struct Hbox {
     /// Widget id
     id: Option<usize>,
 
     /// Widgets
-    pub widgets: Vec<Option<Widget>>,
+    pub widgets: Vec<Box<dyn Widget>>,
 
-    /// Console controler
-    console: Console,
+    /// Children widths, `Constraint::Fill(1)` when missing.
+    constraints: Vec<Constraint>,
 
-    /// Wrap around.
-    wrap: bool,
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
 
     /// Widget shape.
     pub shape: Rect,
 }
 
 impl Hbox {
-    pub fn new(widgets: Vec<Option<Widget>>) -> Self {
+    pub fn new(widgets: Vec<Box<dyn Widget>>) -> Self {
         Self {
             id: None,
             widgets,
-            console: Console::new(),
+            constraints: Vec::new(),
             shape: Rect::default(),
-            wrap: false,
+            block: None,
         }
     }
-    pub fn id(&self) -> Option<usize> {
-        self.id
+
+    /// * Set children widths, in the same order as the widgets.
+    /// * Children without a constraint get `Constraint::Fill(1)`.
+    pub fn constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// * Area of each child inside `rect`, inside the block if wrapped.
+    pub fn layout(&self, rect: Rect) -> Vec<Rect> {
+        let rect = self.block.as_ref().map_or(rect, |b| b.inner(rect));
+        let constraints: Vec<Constraint> = (0..self.widgets.len())
+            .map(|i| {
+                self.constraints
+                    .get(i)
+                    .copied()
+                    .unwrap_or(Constraint::Fill(1))
+            })
+            .collect();
+        split(rect, Direction::Horizontal, &constraints)
     }
-    pub fn set_id(mut self, id: usize) -> Self {
-        self.id = Some(id);
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
         self
     }
 
     /// Check if Widget's wrap around.
     pub fn is_wrap(&self) -> bool {
-        self.wrap
+        self.block.is_some()
     }
 
     /// Wrap around
-    pub fn wrap() {}
-
-    /// Render the widget on console.
-    pub fn render(&mut self, rect: Rect) {
-        let n = self.widgets.len();
-        let ww = rect.w / n as f32;
-        for idx in 0..n {
-            let mut padding = 1.0 as f32;
-            if let Widget::Vbox(_) | Widget::Hbox(_) = self.widgets[idx].as_ref().unwrap() {
-                padding = 0.0;
-            }
-            self.widgets[idx].as_mut().unwrap().render(Rect::new(
-                (rect.x + ww * idx as f32).floor(),
-                rect.y + padding,
-                ww,
-                rect.h,
-            ));
-        }
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
     }
-}
 
-impl AsWidget for Hbox {
-    fn as_widget(self) -> Option<Widget> {
-        Some(Widget::Hbox(self))
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
     }
 }
 
-impl EventHandler for Hbox {
-    fn start(&mut self) {
-        enable_raw_mode().unwrap();
-        self.console.clear();
-        loop {
-            let (mx, my) = self.console.max_xy();
-            let rect = Rect::new(0.0, 0.0, mx, my);
-            self.render(rect);
-            self.handle_event();
+impl Widget for Hbox {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        if let Some(block) = &self.block {
+            block.draw(rect, buf);
+        }
+        let rects = self.layout(rect);
+        for (w, r) in self.widgets.iter_mut().zip(rects) {
+            render_child(w.as_mut(), r, buf);
         }
+
+        // required.
+        self.shape = rect;
     }
 
-    fn handle_event(&mut self) {
-        let event = read().unwrap();
-        match event {
-            Event::Resize(_, _) => self.console.clear(),
-            Event::Mouse(MouseEvent {
-                kind, column, row, ..
-            }) => {
-                if kind == MouseEventKind::Down(MouseButton::Left) {
-                    // loop over the widgets
-                    // check if the Cursor after the click in Widget area
-                    // then trigger the callback
-                    for wi in 0..self.widgets.len() {
-                        let shape = self.widgets[wi].as_ref().unwrap().shape();
-                        if shape.x as u16 <= column
-                            && shape.w as u16 >= column
-                            && shape.y as u16 == row
-                        {
-                            if let Widget::Button(_) = self.widgets[wi].as_ref().unwrap() {
-                                let clicked_widget = self.widgets[wi].take();
-                                self.widgets[wi] = self.trigger_callback(clicked_widget);
-
-                                self.console.clear();
-                            }
-                        }
-
-                        // Same process but for inner Widgets.
-                        if let Widget::Hbox(hb) = self.widgets[wi].as_mut().unwrap() {
-                            inner_hbox_render(hb, column, row);
-                        }
-
-                        // Same process but for inner Widgets.
-                        if let Widget::Vbox(vb) = self.widgets[wi].as_mut().unwrap() {
-                            inner_vbox_render(vb, column, row);
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-        if event == Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)) {
-            disable_raw_mode().unwrap();
-            self.console.clear();
-            std::process::exit(0);
-        };
+    fn shape(&self) -> &Rect {
+        &self.shape
     }
 
-    fn trigger_callback(&mut self, clicked_widget: Option<Widget>) -> Option<Widget> {
-        if let Widget::Button(btn) = clicked_widget.as_ref().unwrap() {
-            match btn.callback {
-                Some(ref f) => {
-                    let rid = btn
-                        .rid
-                        .expect("Please make sure you set the rid through .set_rid(rid: usize)");
-
-                    for wi in 0..self.widgets.len() {
-                        match self.widgets[wi].as_mut().unwrap() {
-                            // Inner Widgets
-                            Widget::Vbox(vb) => {
-                                if inner_vbox_trigger(vb, f, rid) {
-                                    return clicked_widget;
-                                }
-                            }
-                            // Inner Widgets
-                            Widget::Hbox(hb) => {
-                                if inner_hbox_trigger(hb, f, rid) {
-                                    return clicked_widget;
-                                }
-                            }
-                            // Outer Widgets
-                            w @ _ => {
-                                if let Some(id) = w.id() {
-                                    if rid == id {
-                                        self.widgets[wi] = Some(
-                                            f(self.widgets[wi].take().as_mut().unwrap()).take(),
-                                        );
-                                        return clicked_widget;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                _ => {}
-            }
-        }
-        clicked_widget
+    fn id(&self) -> Option<usize> {
+        self.id
     }
-}
 
-pub fn inner_hbox_render(hbox: &mut Hbox, column: u16, row: u16) {
-    for wi in 0..hbox.widgets.len() {
-        let shape = hbox.widgets[wi].as_ref().unwrap().shape();
-        if shape.x as u16 <= column
-            && (shape.w + shape.x - 1.0) as u16 >= column
-            && (shape.y - 0.0) as u16 == row
-        {
-            if let Widget::Button(_) = hbox.widgets[wi].as_ref().unwrap() {
-                let clicked_widget = hbox.widgets[wi].take();
-                hbox.widgets[wi] = hbox.trigger_callback(clicked_widget);
-                crate::Console::new().flush();
-            }
-        }
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        // tallest child, 3 rows for children that don't know.
+        let rects = self.layout(Rect::new(0.0, 0.0, width, 0.0));
+        self.widgets
+            .iter()
+            .zip(rects)
+            .map(|(w, r)| w.preferred_height(r.w).unwrap_or(3.0))
+            .reduce(f32::max)
+            .map(|h| h + self.block.as_ref().map_or(0.0, |b| b.vertical_space()))
+    }
 
-        if let Widget::Hbox(hb) = hbox.widgets[wi].as_mut().unwrap() {
-            inner_hbox_render(hb, column, row);
-        }
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        self.widgets.iter_mut().any(|w| w.handle_event(event, ctx))
+    }
 
-        if let Widget::Vbox(vb) = hbox.widgets[wi].as_mut().unwrap() {
-            inner_vbox_render(vb, column, row);
-        }
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &self.widgets
     }
-}
 
-pub fn inner_hbox_trigger(
-    hbox: &mut Hbox,
-    f: impl Fn(&mut Widget) -> &mut Widget,
-    rid: usize,
-) -> bool {
-    for wi in 0..hbox.widgets.len() {
-        if let Some(w) = hbox.widgets[wi].as_ref() {
-            if let Some(id) = w.id() {
-                if rid == id {
-                    hbox.widgets[wi] = Some(f(hbox.widgets[wi].take().as_mut().unwrap()).take());
-                    return true;
-                }
-            }
-        }
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut self.widgets
     }
-    false
 }
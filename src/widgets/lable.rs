@@ -1,5 +1,7 @@
-use super::{AsWidget, Rect, Widget};
-use crate::{functions::Alignment, Console};
+use super::{IntoId, Rect, Widget};
+use crate::{
+    functions::Alignment, spans_width, wrap_spans, Block, Buffer, Span, Style, TextWrap, VAlignment,
+};
 
 pub struct Lable {
     /// Widget id
@@ -8,14 +10,26 @@ pub struct Lable {
     /// Text to display
     pub text: String,
 
+    /// Styled pieces of text, replace `text` when not empty.
+    pub spans: Vec<Span>,
+
     /// Text Alignment
     alignment: Alignment,
 
-    /// Wrap around.
-    wrap: bool,
+    /// Vertical Alignment
+    v_alignment: VAlignment,
+
+    /// How long lines are split into rows.
+    text_wrap: TextWrap,
+
+    /// Widget style.
+    style: Style,
 
-    /// Console controler
-    pub console: Console,
+    /// Border style.
+    border_style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
 
     /// Widget shape.
     pub shape: Rect,
@@ -27,29 +41,37 @@ impl Lable {
         Self {
             id: None,
             text: text.into(),
+            spans: Vec::new(),
             alignment: Alignment::Left,
-            wrap: false,
-            console: Console::new(),
+            v_alignment: VAlignment::Middle,
+            text_wrap: TextWrap::None,
+            style: Style::default(),
+            border_style: Style::default(),
+            block: None,
             shape: Rect::default(),
         }
     }
 
-    pub fn id(&self) -> Option<usize> {
-        self.id
-    }
-    pub fn set_id(mut self, id: usize) -> Self {
-        self.id = Some(id);
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
         self
     }
 
     /// Check if Widget's wrap around.
     pub fn is_wrap(&self) -> bool {
-        self.wrap
+        self.block.is_some()
     }
 
     /// Wrap around
     pub fn wrap(mut self) -> Self {
-        self.wrap = true;
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
         self
     }
 
@@ -59,47 +81,115 @@ impl Lable {
         self
     }
 
-    /// Render text with consideration the alignment
-    fn render_text(&mut self, rect: &Rect) {
-        match self.alignment {
-            Alignment::Left => self.console.draw((rect.x + 1.0, rect.y), &self.text),
-            Alignment::Center => self.console.draw(
-                (
-                    rect.x + 1.0 + ((rect.w * 0.5) - (self.text.len() as f32 * 0.5)),
-                    rect.y,
-                ),
-                &self.text,
-            ),
-            Alignment::Right => self.console.draw(
-                (rect.x - 1.0 + (rect.w - self.text.len() as f32), rect.y),
-                &self.text,
-            ),
+    /// * Set the style of the whole widget, text included.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style of the border, drawn over the widget and block styles.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// * Display styled pieces of text instead of `text`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::functions::*;
+    ///
+    /// lable("").spans(vec![
+    ///     Span::raw("db: "),
+    ///     Span::styled("up", Style::new().fg(Color::Green)),
+    /// ]);
+    /// ```
+    pub fn spans(mut self, spans: Vec<Span>) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    /// Set vertical alignment for the text.
+    pub fn v_align(mut self, v_alignment: VAlignment) -> Self {
+        self.v_alignment = v_alignment;
+        self
+    }
+
+    /// * Set how lines longer than the widget are split, cut with `…` by default.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::functions::*;
+    ///
+    /// let l = lable("Lorem ipsum dolor sit amet").text_wrap(TextWrap::Word);
+    ///
+    /// // 1 column of padding on each side.
+    /// assert_eq!(l.preferred_height(14.0), Some(3.0));
+    /// ```
+    pub fn text_wrap(mut self, text_wrap: TextWrap) -> Self {
+        self.text_wrap = text_wrap;
+        self
+    }
+
+    /// * Area of the text inside `rect`.
+    fn content(&self, rect: Rect) -> Rect {
+        match &self.block {
+            Some(block) => block.inner(rect),
+            // one column of padding on each side.
+            None => Rect::new(rect.x + 1.0, rect.y, (rect.w - 2.0).max(0.0), rect.h),
         }
     }
 
-    /// Render the widget on console.
-    pub fn render(&mut self, rect: Rect) {
-        if !self.wrap {
-            self.render_text(&rect);
-            return;
+    /// * Rows of text for a text area `width` columns wide.
+    fn rows(&self, width: f32) -> Vec<Vec<Span>> {
+        let available = width.max(0.0) as usize;
+        match self.spans.is_empty() {
+            true => wrap_spans(&[Span::raw(self.text.as_str())], available, self.text_wrap),
+            false => wrap_spans(&self.spans, available, self.text_wrap),
         }
+    }
 
-        // wrap around
-        let top = "┌".to_string() + &"─".repeat(rect.w as usize - 2) + "┐";
-        let donw = "└".to_string() + &"─".repeat(rect.w as usize - 2) + "┘";
-        self.console.draw((rect.x, rect.y - 1.0), &top);
-        self.console.draw((rect.x, rect.y), "│");
-        self.render_text(&rect);
-        self.console.draw((rect.x + rect.w - 1.0, rect.y), "│");
-        self.console.draw((rect.x, rect.y + 1.0), &donw);
+    /// Render text with consideration the alignment
+    fn render_text(&mut self, rect: &Rect, buf: &mut Buffer) {
+        let rows = self.rows(rect.w);
+        let available = rect.w.max(0.0) as usize;
+        let height = rect.h.max(0.0) as usize;
+        let y = rect.y + self.v_alignment.offset(height, rows.len()) as f32;
+        for (i, row) in rows.iter().take(height).enumerate() {
+            let x = self.alignment.offset(available, spans_width(row));
+            buf.draw_spans((rect.x + x as f32, y + i as f32), row);
+        }
+    }
+}
 
+impl Widget for Lable {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
         // required.
         self.shape = rect;
+        buf.set_style(self.shape, self.style);
+
+        if let Some(block) = &self.block {
+            let border_style = block.border_style.patch(self.border_style);
+            block.draw_with(rect, buf, block.border_type, border_style);
+        }
+        let content = self.content(rect);
+        self.render_text(&content, buf);
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
     }
-}
 
-impl AsWidget for Lable {
-    fn as_widget(self) -> Option<Widget> {
-        Some(Widget::Lable(self))
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        let content = self.content(Rect::new(0.0, 0.0, width, 0.0));
+        let space = self.block.as_ref().map_or(0.0, |b| b.vertical_space());
+        Some(self.rows(content.w).len() as f32 + space)
     }
 }
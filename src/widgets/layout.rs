@@ -0,0 +1,199 @@
+use super::Rect;
+
+/// * Size rule for one child of a `Vbox` (rows) or `Hbox` (columns).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// Exactly `n` cells.
+    Length(u16),
+
+    /// `n`% of the available cells.
+    Percentage(u16),
+
+    /// At least `n` cells, grows like `Fill(1)`.
+    Min(u16),
+
+    /// At most `n` cells, grows like `Fill(1)`.
+    Max(u16),
+
+    /// Share of the cells left after the other constraints, proportional to `n`.
+    Fill(u16),
+}
+
+/// * Direction to split a Rect in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// Top to bottom, constraints are heights.
+    Vertical,
+
+    /// Left to right, constraints are widths.
+    Horizontal,
+}
+
+/// * Split `rect` into one Rect per constraint.
+/// * Fixed sizes (`Length`, `Percentage`, `Min`) are taken first, then what's left
+///   is shared by `Min`, `Max` and `Fill` by weight. Rects that don't fit are cut
+///   at the edge of `rect`, or empty.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let rects = split(
+///     Rect::new(0.0, 0.0, 80.0, 24.0),
+///     Direction::Vertical,
+///     &[Constraint::Length(3), Constraint::Fill(1), Constraint::Length(1)],
+/// );
+///
+/// assert_eq!(rects[0], Rect::new(0.0, 0.0, 80.0, 3.0));
+/// assert_eq!(rects[1], Rect::new(0.0, 3.0, 80.0, 20.0));
+/// assert_eq!(rects[2], Rect::new(0.0, 23.0, 80.0, 1.0));
+/// ```
+///
+/// Every constraint, 30 columns wide:
+///
+/// ```
+/// use simple_tui::functions::*;
+/// use Constraint::*;
+///
+/// let widths = |constraints: &[Constraint]| -> Vec<f32> {
+///     let rects = split(Rect::new(0.0, 0.0, 30.0, 1.0), Direction::Horizontal, constraints);
+///     rects.iter().map(|r| r.w).collect()
+/// };
+///
+/// assert_eq!(widths(&[Length(10), Fill(1)]), [10.0, 20.0]);
+/// assert_eq!(widths(&[Percentage(20), Percentage(50), Fill(1)]), [6.0, 15.0, 9.0]);
+/// assert_eq!(widths(&[Percentage(150), Fill(1)]), [30.0, 0.0]);
+/// // `Min` takes its cells first, then grows like `Fill(1)`.
+/// assert_eq!(widths(&[Min(10), Fill(1)]), [20.0, 10.0]);
+/// // `Max` stops at its cap, the others share what it leaves.
+/// assert_eq!(widths(&[Max(5), Fill(1)]), [5.0, 25.0]);
+/// assert_eq!(widths(&[Max(5), Max(8), Fill(2)]), [5.0, 8.0, 17.0]);
+/// assert_eq!(widths(&[Max(20), Fill(1)]), [15.0, 15.0]);
+/// assert_eq!(widths(&[Fill(1), Fill(2)]), [10.0, 20.0]);
+/// // rounding leftovers go to the first children.
+/// assert_eq!(widths(&[Fill(1), Fill(1), Fill(1), Fill(1)]), [8.0, 8.0, 7.0, 7.0]);
+/// // only `Max` children left under their cap: the rest stays empty.
+/// assert_eq!(widths(&[Max(10), Max(5)]), [10.0, 5.0]);
+/// ```
+///
+/// More than the area, the last Rects are cut at its edge:
+///
+/// ```
+/// use simple_tui::functions::*;
+/// use Constraint::*;
+///
+/// let rects = |constraints: &[Constraint]| -> Vec<(f32, f32)> {
+///     let rects = split(Rect::new(0.0, 0.0, 30.0, 1.0), Direction::Horizontal, constraints);
+///     rects.iter().map(|r| (r.x, r.w)).collect()
+/// };
+///
+/// assert_eq!(rects(&[Length(20), Length(20)]), [(0.0, 20.0), (20.0, 10.0)]);
+/// assert_eq!(rects(&[Length(40), Fill(1)]), [(0.0, 30.0), (30.0, 0.0)]);
+/// assert_eq!(rects(&[Min(20), Min(20), Max(5)]), [(0.0, 20.0), (20.0, 10.0), (30.0, 0.0)]);
+/// assert_eq!(rects(&[Percentage(80), Percentage(80)]), [(0.0, 24.0), (24.0, 6.0)]);
+/// assert_eq!(rects(&[Length(10)]), [(0.0, 10.0)]);
+/// assert_eq!(rects(&[]), []);
+/// ```
+pub fn split(rect: Rect, direction: Direction, constraints: &[Constraint]) -> Vec<Rect> {
+    let total = match direction {
+        Direction::Vertical => rect.h,
+        Direction::Horizontal => rect.w,
+    }
+    .max(0.0) as u32;
+
+    let sizes = solve(total, constraints);
+
+    let mut pos = 0;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let start = pos.min(total);
+            let size = size.min(total - start);
+            pos += size;
+            match direction {
+                Direction::Vertical => {
+                    Rect::new(rect.x, rect.y + start as f32, rect.w, size as f32)
+                }
+                Direction::Horizontal => {
+                    Rect::new(rect.x + start as f32, rect.y, size as f32, rect.h)
+                }
+            }
+        })
+        .collect()
+}
+
+/// * Sizes for `constraints` sharing `total` cells.
+fn solve(total: u32, constraints: &[Constraint]) -> Vec<u32> {
+    let mut sizes: Vec<u32> = constraints
+        .iter()
+        .map(|c| match *c {
+            Constraint::Length(n) | Constraint::Min(n) => n as u32,
+            Constraint::Percentage(p) => total * p.min(100) as u32 / 100,
+            Constraint::Max(_) | Constraint::Fill(_) => 0,
+        })
+        .collect();
+
+    // Flexible children and their weight, `Max` ones leave once they reach their cap.
+    let mut flexible: Vec<(usize, u32)> = constraints
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| match *c {
+            Constraint::Min(_) | Constraint::Max(_) => Some((i, 1)),
+            Constraint::Fill(w) => Some((i, w as u32)),
+            _ => None,
+        })
+        .collect();
+
+    let used: u32 = sizes.iter().sum();
+    let mut left = total.saturating_sub(used);
+    while left > 0 && !flexible.is_empty() {
+        let weights: u32 = flexible.iter().map(|(_, w)| w).sum();
+        if weights == 0 {
+            break;
+        }
+
+        // Shares rounded down, the remainder goes to the first children.
+        let mut shares: Vec<u32> = flexible.iter().map(|(_, w)| left * w / weights).collect();
+        let mut remainder = left - shares.iter().sum::<u32>();
+        for (share, (_, w)) in shares.iter_mut().zip(&flexible) {
+            if remainder == 0 {
+                break;
+            }
+            if *w > 0 {
+                *share += 1;
+                remainder -= 1;
+            }
+        }
+
+        // Cap `Max` children, and share again what they couldn't take.
+        let mut capped = false;
+        for (share, (i, _)) in shares.iter_mut().zip(&flexible) {
+            if let Constraint::Max(max) = constraints[*i] {
+                if *share >= max as u32 {
+                    *share = max as u32;
+                    capped = true;
+                }
+            }
+        }
+        if !capped {
+            for (share, (i, _)) in shares.iter().zip(&flexible) {
+                sizes[*i] += share;
+            }
+            break;
+        }
+        for (share, (i, _)) in shares.iter().zip(&flexible) {
+            if let Constraint::Max(max) = constraints[*i] {
+                if *share == max as u32 {
+                    sizes[*i] = *share;
+                    left -= share;
+                }
+            }
+        }
+        flexible.retain(
+            |(i, _)| !matches!(constraints[*i], Constraint::Max(max) if sizes[*i] == max as u32),
+        );
+    }
+
+    sizes
+}
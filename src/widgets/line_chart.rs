@@ -0,0 +1,312 @@
+use super::{canvas::BrailleGrid, IntoId, Rect, Shape, Widget};
+use crate::{text_width, truncate, Alignment, Block, Buffer, Color, Style};
+
+/// * Named series of `(x, y)` points, joined by lines on a `LineChart`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    /// Name in the legend, hidden when empty.
+    pub name: String,
+
+    /// Points, in drawing order.
+    pub data: Vec<(f64, f64)>,
+
+    /// Color of the lines and of the legend.
+    pub color: Color,
+}
+
+impl Dataset {
+    /// * Construct new dataset, drawn with the terminal color.
+    pub fn new(name: impl Into<String>, data: Vec<(f64, f64)>) -> Self {
+        Self {
+            name: name.into(),
+            data,
+            color: Color::Reset,
+        }
+    }
+
+    /// Set the color of the lines and of the legend.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// * Short text for an axis value: no decimals for integers, at most 2 otherwise.
+fn format_value(value: f64) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// * Bounds from the data when not set, never empty.
+fn bounds(set: Option<[f64; 2]>, values: impl Iterator<Item = f64>) -> [f64; 2] {
+    let [min, max] = set.unwrap_or_else(|| {
+        values.fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], v| {
+            [min.min(v), max.max(v)]
+        })
+    });
+    match (min.is_finite() && max.is_finite(), min < max) {
+        (false, _) => [0.0, 1.0],
+        (true, false) => [min - 1.0, min + 1.0],
+        (true, true) => [min, max],
+    }
+}
+
+/// * Datasets plotted with Braille lines, over an `x` axis and a `y` axis with their bounds as labels.
+/// * Bounds follow the data unless set, the legend shows the named datasets.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+/// let mut chart = line_chart(vec![Dataset::new("", vec![(0.0, 0.0), (5.0, 10.0)])]);
+/// terminal.draw(|rect, buf| chart.render(rect, buf)).unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec!["10│  ⢀⠤⠊", " 0│⡠⠒⠁  ", "  └─────", "   0   5"]
+/// );
+/// ```
+pub struct LineChart {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Plotted series.
+    pub datasets: Vec<Dataset>,
+
+    /// `x` at the left and right of the plot, from the data by default.
+    x_bounds: Option<[f64; 2]>,
+
+    /// `y` at the bottom and top of the plot, from the data by default.
+    y_bounds: Option<[f64; 2]>,
+
+    /// Name of the `x` axis, under the labels.
+    x_title: Option<String>,
+
+    /// Name of the `y` axis, above the plot.
+    y_title: Option<String>,
+
+    /// Style of the axes, labels and titles, drawn over the widget style.
+    axis_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl LineChart {
+    /// * Construct new line chart.
+    pub fn new(datasets: Vec<Dataset>) -> Self {
+        Self {
+            id: None,
+            datasets,
+            x_bounds: None,
+            y_bounds: None,
+            x_title: None,
+            y_title: None,
+            axis_style: Style::default(),
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Add a dataset.
+    pub fn dataset(mut self, dataset: Dataset) -> Self {
+        self.datasets.push(dataset);
+        self
+    }
+
+    /// * Set `x` at the left and right of the plot, instead of following the data.
+    pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+        self.x_bounds = Some([min, max]);
+        self
+    }
+
+    /// * Set `y` at the bottom and top of the plot, instead of following the data.
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = Some([min, max]);
+        self
+    }
+
+    /// Set the name of the `x` axis.
+    pub fn x_title(mut self, title: impl Into<String>) -> Self {
+        self.x_title = Some(title.into());
+        self
+    }
+
+    /// Set the name of the `y` axis.
+    pub fn y_title(mut self, title: impl Into<String>) -> Self {
+        self.y_title = Some(title.into());
+        self
+    }
+
+    /// * Set the style of the axes, labels and titles.
+    pub fn axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Draw the names of the datasets in the top right corner of `plot`.
+    fn draw_legend(&self, plot: Rect, buf: &mut Buffer) {
+        let named: Vec<&Dataset> = self
+            .datasets
+            .iter()
+            .filter(|d| !d.name.is_empty())
+            .collect();
+        let width = named
+            .iter()
+            .map(|d| text_width(&d.name) + 2)
+            .max()
+            .unwrap_or(0);
+        if named.is_empty() || named.len() > plot.h as usize || width > plot.w as usize {
+            return;
+        }
+        let x = plot.x + plot.w - width as f32;
+        for (row, dataset) in named.into_iter().enumerate() {
+            let y = plot.y + row as f32;
+            buf.draw_styled((x, y), &" ".repeat(width), self.style);
+            buf.draw_styled((x, y), "•", Style::default().fg(dataset.color));
+            buf.draw_styled((x + 2.0, y), &dataset.name, self.axis_style);
+        }
+    }
+}
+
+impl Widget for LineChart {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(rect, buf);
+                block.inner(rect)
+            }
+            None => rect,
+        };
+        let points = || self.datasets.iter().flat_map(|d| d.data.iter());
+        let x_bounds = bounds(self.x_bounds, points().map(|p| p.0));
+        let y_bounds = bounds(self.y_bounds, points().map(|p| p.1));
+
+        // rows: y title, plot, axis, x labels, x title.
+        let top = self.y_title.is_some() as usize;
+        let bottom = 2 + self.x_title.is_some() as usize;
+        let plot_h = (content.h.max(0.0) as usize).saturating_sub(top + bottom);
+        let y_labels = [format_value(y_bounds[1]), format_value(y_bounds[0])];
+        let label_w = y_labels.iter().map(|l| text_width(l)).max().unwrap_or(0);
+        let plot_w = (content.w.max(0.0) as usize).saturating_sub(label_w + 1);
+        if plot_h == 0 || plot_w == 0 {
+            return;
+        }
+        let plot = Rect::new(
+            content.x + label_w as f32 + 1.0,
+            content.y + top as f32,
+            plot_w as f32,
+            plot_h as f32,
+        );
+        let axis_y = plot.y + plot.h;
+
+        // axes
+        let axis_x = plot.x - 1.0;
+        for y in 0..plot_h {
+            buf.draw_styled((axis_x, plot.y + y as f32), "│", self.axis_style);
+        }
+        let x_axis = "└".to_string() + &"─".repeat(plot_w);
+        buf.draw_styled((axis_x, axis_y), &x_axis, self.axis_style);
+
+        // labels
+        let [max, min] = &y_labels;
+        let right = |label: &str| (label_w - text_width(label)) as f32;
+        buf.draw_styled((content.x + right(max), plot.y), max, self.axis_style);
+        if plot_h > 1 {
+            let y = axis_y - 1.0;
+            buf.draw_styled((content.x + right(min), y), min, self.axis_style);
+        }
+        let (first, last) = (format_value(x_bounds[0]), format_value(x_bounds[1]));
+        let last_x = plot.x + plot.w - text_width(&last) as f32;
+        if text_width(&first) < plot_w.saturating_sub(text_width(&last)) {
+            buf.draw_styled((plot.x, axis_y + 1.0), &first, self.axis_style);
+        }
+        buf.draw_styled(
+            (last_x.max(plot.x), axis_y + 1.0),
+            &truncate(&last, plot_w),
+            self.axis_style,
+        );
+
+        // titles
+        if let Some(title) = &self.y_title {
+            let title = truncate(title, content.w.max(0.0) as usize);
+            buf.draw_styled((content.x, content.y), &title, self.axis_style);
+        }
+        if let Some(title) = &self.x_title {
+            let title = truncate(title, plot_w);
+            let x = plot.x + Alignment::Center.offset(plot_w, text_width(&title)) as f32;
+            buf.draw_styled((x, axis_y + 2.0), &title, self.axis_style);
+        }
+
+        let mut grid = BrailleGrid::new(plot_w, plot_h);
+        for dataset in &self.datasets {
+            let shapes: Vec<Shape> = match dataset.data.as_slice() {
+                [point] => vec![Shape::Points {
+                    coords: vec![*point],
+                    color: dataset.color,
+                }],
+                data => data
+                    .windows(2)
+                    .map(|pair| Shape::Line {
+                        x1: pair[0].0,
+                        y1: pair[0].1,
+                        x2: pair[1].0,
+                        y2: pair[1].1,
+                        color: dataset.color,
+                    })
+                    .collect(),
+            };
+            for shape in &shapes {
+                grid.paint(shape, x_bounds, y_bounds);
+            }
+        }
+        grid.draw(plot.x, plot.y, buf);
+        self.draw_legend(plot, buf);
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+}
@@ -0,0 +1,381 @@
+use super::{Id, IntoId, Rect, Widget};
+use crate::{text_width, truncate, Block, Buffer, Context, Style};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
+use std::{any::Any, rc::Rc};
+
+/// * Callback triggered by `on_select` and `on_activate`, receives the item index and text.
+pub type ListCallback = Rc<dyn Fn(&mut dyn Widget, usize, &str)>;
+
+/// * Message sent by `on_select_msg` and `on_activate_msg`, made from the item index and text.
+pub type ListMessage = Rc<dyn Fn(usize, &str) -> Box<dyn Any>>;
+
+/// * Selectable list of items, one per row.
+/// * `Up`/`Down`, `PageUp`/`PageDown`, `Home`/`End` or a click select an item,
+///   `Enter`/`Space` or a click on the selected item activate it.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+/// let mut menu = list(vec!["New", "Open", "Quit"]).select(1);
+/// terminal.draw(|rect, buf| menu.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["  New     ", "> Open    ", "  Quit    "]);
+/// assert_eq!(menu.selected_item(), Some("Open"));
+/// ```
+pub struct List {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Items to display.
+    pub items: Vec<String>,
+
+    /// Selected item.
+    selected: Option<usize>,
+
+    /// First visible item.
+    offset: usize,
+
+    /// Scroll to the selected item on the next render.
+    follow: bool,
+
+    /// Drawn before the selected item.
+    highlight_symbol: String,
+
+    /// Style of the selected row, drawn over the widget style.
+    highlight_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the selection changed.
+    pub on_select: Option<ListCallback>,
+
+    /// Callback after an item is activated.
+    pub on_activate: Option<ListCallback>,
+
+    /// Message sent after the selection changed.
+    pub select_msg: Option<ListMessage>,
+
+    /// Message sent after an item is activated.
+    pub activate_msg: Option<ListMessage>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl List {
+    /// * Construct new list, nothing selected.
+    pub fn new<T: Into<String>>(items: Vec<T>) -> Self {
+        Self {
+            id: None,
+            items: items.into_iter().map(Into::into).collect(),
+            selected: None,
+            offset: 0,
+            follow: false,
+            highlight_symbol: "> ".into(),
+            highlight_style: Style::default().reversed(),
+            style: Style::default(),
+            block: None,
+            focused: false,
+            rid: None,
+            on_select: None,
+            on_activate: None,
+            select_msg: None,
+            activate_msg: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Select the `index`th item.
+    pub fn select(mut self, index: usize) -> Self {
+        self.set_selected(Some(index));
+        self
+    }
+
+    /// * Set the symbol drawn before the selected item, `"> "` by default.
+    pub fn highlight_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.highlight_symbol = symbol.into();
+        self
+    }
+
+    /// * Set the style of the selected row, reversed by default.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// * Storing the callback, triggered with the new selection.
+    pub fn on_select(mut self, f: impl Fn(&mut dyn Widget, usize, &str) + 'static) -> Self {
+        self.on_select = Some(Rc::new(f));
+        self
+    }
+
+    /// * Storing the callback, triggered with the activated item.
+    pub fn on_activate(mut self, f: impl Fn(&mut dyn Widget, usize, &str) + 'static) -> Self {
+        self.on_activate = Some(Rc::new(f));
+        self
+    }
+
+    /// * Send the message made by `f` from the new selection to the `App`. See `App`.
+    pub fn on_select_msg<M: 'static>(mut self, f: impl Fn(usize, &str) -> M + 'static) -> Self {
+        self.select_msg = Some(Rc::new(move |i, item| Box::new(f(i, item))));
+        self
+    }
+
+    /// * Send the message made by `f` from the activated item to the `App`. See `App`.
+    pub fn on_activate_msg<M: 'static>(mut self, f: impl Fn(usize, &str) -> M + 'static) -> Self {
+        self.activate_msg = Some(Rc::new(move |i, item| Box::new(f(i, item))));
+        self
+    }
+
+    /// Selected item index.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selected item text.
+    pub fn selected_item(&self) -> Option<&str> {
+        self.items.get(self.selected?).map(String::as_str)
+    }
+
+    /// * Select the `index`th item (kept inside the list), or nothing.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        self.selected = match self.items.len() {
+            0 => None,
+            len => index.map(|i| i.min(len - 1)),
+        };
+        self.follow = true;
+    }
+
+    /// * Area of the items inside the shape.
+    fn content(&self) -> Rect {
+        self.block
+            .as_ref()
+            .map_or(self.shape, |b| b.inner(self.shape))
+    }
+
+    /// * Move the selection with a keystroke.
+    /// * Return `None` if the keystroke wasn't used, `Some(true)` to activate the selected item.
+    fn handle_key(&mut self, key: &KeyEvent) -> Option<bool> {
+        if key.kind == KeyEventKind::Release || self.items.is_empty() {
+            return None;
+        }
+        let page = (self.content().h as usize).saturating_sub(1).max(1);
+        if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) && self.selected.is_some() {
+            return Some(true);
+        }
+        let next = navigate(key.code, self.selected, self.items.len(), page)?;
+        self.set_selected(Some(next));
+        Some(false)
+    }
+
+    /// * Queue `callback` with the selected item on the related Widget, and send `message`.
+    fn trigger(
+        &mut self,
+        callback: Option<ListCallback>,
+        message: Option<ListMessage>,
+        ctx: &mut Context,
+    ) {
+        let Some((i, item)) = self.selected.and_then(|i| Some((i, self.items.get(i)?))) else {
+            return;
+        };
+        if let Some(msg) = message {
+            ctx.send_any(msg(i, item));
+        }
+        let Some(f) = callback else {
+            return;
+        };
+        let item = item.clone();
+        match self.rid.clone() {
+            Some(rid) => ctx.trigger(rid, move |w| f(w, i, &item)),
+            None => f(self, i, &item),
+        }
+    }
+}
+
+/// * Selection after a navigation key (`Up`/`Down`, `PageUp`/`PageDown`, `Home`/`End`)
+///   among `len` rows, `None` if the key isn't one of them.
+pub(super) fn navigate(
+    code: KeyCode,
+    current: Option<usize>,
+    len: usize,
+    page: usize,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let next = match (code, current) {
+        (KeyCode::Up, Some(i)) => i.saturating_sub(1),
+        (KeyCode::Down, Some(i)) => (i + 1).min(last),
+        (KeyCode::PageUp, Some(i)) => i.saturating_sub(page),
+        (KeyCode::PageDown, Some(i)) => (i + page).min(last),
+        (KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown, None) => 0,
+        (KeyCode::Home, _) => 0,
+        (KeyCode::End, _) => last,
+        _ => return None,
+    };
+    Some(next)
+}
+
+impl Widget for List {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+        if let Some(block) = &self.block {
+            block.draw(rect, buf);
+        }
+
+        let content = self.content();
+        let height = content.h.max(0.0) as usize;
+        if self.follow {
+            if let Some(i) = self.selected {
+                if i < self.offset {
+                    self.offset = i;
+                } else if i >= self.offset + height {
+                    self.offset = i + 1 - height.min(i + 1);
+                }
+            }
+            self.follow = false;
+        }
+        self.offset = self.offset.min(self.items.len().saturating_sub(height));
+
+        let width = content.w.max(0.0) as usize;
+        let symbol_width = text_width(&self.highlight_symbol);
+        let blank = " ".repeat(symbol_width);
+        for (row, i) in (self.offset..self.items.len()).take(height).enumerate() {
+            let y = content.y + row as f32;
+            let selected = self.selected == Some(i);
+            let symbol = if selected {
+                &self.highlight_symbol
+            } else {
+                &blank
+            };
+            let text = symbol.clone() + &self.items[i];
+            buf.draw((content.x, y), &truncate(&text, width));
+            if selected {
+                buf.set_style(
+                    Rect::new(content.x, y, content.w, 1.0),
+                    self.highlight_style,
+                );
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        let space = self.block.as_ref().map_or(0.0, |b| b.vertical_space());
+        Some(self.items.len() as f32 + space)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let before = self.selected;
+        let activate = match event {
+            Event::Key(key) if self.focused => match self.handle_key(key) {
+                Some(activate) => activate,
+                None => return false,
+            },
+            Event::Mouse(MouseEvent {
+                kind, column, row, ..
+            }) if self.content().contains(*column, *row) => match kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    let i = self.offset + (*row as f32 - self.content().y) as usize;
+                    if i >= self.items.len() {
+                        return true;
+                    }
+                    let again = self.selected == Some(i);
+                    self.set_selected(Some(i));
+                    again
+                }
+                MouseEventKind::ScrollUp => {
+                    self.offset = self.offset.saturating_sub(1);
+                    return true;
+                }
+                MouseEventKind::ScrollDown => {
+                    self.offset += 1;
+                    return true;
+                }
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        if self.selected != before {
+            self.trigger(self.on_select.clone(), self.select_msg.clone(), ctx);
+        }
+        if activate {
+            self.trigger(self.on_activate.clone(), self.activate_msg.clone(), ctx);
+        }
+        true
+    }
+
+    fn restore(&mut self, old: &dyn Widget) {
+        if let Some(old) = old.downcast_ref::<Self>() {
+            self.set_selected(old.selected);
+            self.offset = old.offset;
+            self.follow = old.follow;
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -1,36 +1,183 @@
+use crate::Runner;
+use crossterm::event::Event;
+use std::io;
+
 mod utility;
 pub use utility::*;
 
+mod text;
+pub use text::*;
+
 mod widget;
 pub use widget::*;
 
+mod layout;
+pub use layout::*;
+
+mod context;
+pub use context::*;
+
+mod focus;
+pub use focus::*;
+
+mod store;
+pub use store::*;
+
+mod app;
+pub use app::*;
+
+mod block;
+pub use block::*;
+
 mod vbox;
 pub use vbox::*;
 
 mod hbox;
 pub use hbox::*;
 
+mod scroll_view;
+pub use scroll_view::*;
+
+mod tabs;
+pub use tabs::*;
+
+mod overlay;
+pub use overlay::*;
+
 mod button;
 pub use button::*;
 
 mod lable;
 pub use lable::*;
 
+mod text_input;
+pub use text_input::*;
+
+mod list;
+pub use list::*;
+
+mod table;
+pub use table::*;
+
+mod checkbox;
+pub use checkbox::*;
+
+mod toggle;
+pub use toggle::*;
+
+mod radio_group;
+pub use radio_group::*;
+
+mod progress_bar;
+pub use progress_bar::*;
+
+mod gauge;
+pub use gauge::*;
+
+mod sparkline;
+pub use sparkline::*;
+
+mod bar_chart;
+pub use bar_chart::*;
+
+mod canvas;
+pub use canvas::*;
+
+mod line_chart;
+pub use line_chart::*;
+
+mod dialog;
+pub use dialog::*;
+
 pub trait AsWidget {
-    /// * Convert `T` =>  `Option<Widget>`
-    fn as_widget(self) -> Option<Widget>;
+    /// * Convert `T` =>  `Box<dyn Widget>`
+    #[allow(clippy::wrong_self_convention)]
+    fn as_widget(self) -> Box<dyn Widget>;
+}
+
+impl<W: Widget> AsWidget for W {
+    fn as_widget(self) -> Box<dyn Widget> {
+        Box::new(self)
+    }
 }
 
 /// * Start drawing Widgets on console.
 /// * Handle Mouse, Resize and Keystroke.
 /// * Trigger callbacks.
+/// * Implemented for every Widget, so any Widget can be the root.
 pub trait EventHandler {
-    /// #####  Start rendering the Widgets on screen. \[ *Infinity loop* \]
-    fn start(&mut self);
+    /// #####  Start rendering the Widgets on screen until `Esc` is pressed.
+    /// * Use `Runner` to choose the quit key or the backend.
+    fn run(&mut self) -> io::Result<()>
+    where
+        Self: Sized;
+
+    ///* Send one input event through the Widgets, then run the triggered callbacks.
+    fn dispatch(&mut self, event: &Event) -> Result<(), LookupError>;
+}
+
+impl<W: Widget> EventHandler for W {
+    fn run(&mut self) -> io::Result<()> {
+        Runner::new()?.run(self)
+    }
+
+    fn dispatch(&mut self, event: &Event) -> Result<(), LookupError> {
+        dispatch(self, event)
+    }
+}
+
+/// * Read and update any Widget of the tree by its id, nested as deep as it is.
+/// * Implemented for every Widget, so any container can be searched.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut root = vbox(widgets![
+///     lable("Name:"),
+///     hbox(widgets![lable("").set_id("greeting"), lable("").set_id(7)])
+/// ]);
+/// root.get_mut::<Lable>("greeting").unwrap().text = "Hello".into();
+///
+/// assert_eq!(root.get::<Lable>("greeting").unwrap().text, "Hello");
+/// assert!(root.find_by_id(7).is_some());
+/// assert!(root.get::<Button>(7).is_none());
+/// ```
+pub trait Lookup {
+    /// * Widget with id `id`, the root included.
+    fn find_by_id(&self, id: impl IntoId) -> Option<&dyn Widget>;
+
+    /// * Mutable Widget with id `id`, the root included.
+    fn find_by_id_mut(&mut self, id: impl IntoId) -> Option<&mut dyn Widget>;
+
+    /// * Widget with id `id`, `None` if it isn't there or isn't a `T`.
+    fn get<T: Widget>(&self, id: impl IntoId) -> Option<&T> {
+        self.find_by_id(id)?.downcast_ref()
+    }
+
+    /// * Mutable Widget with id `id`, `None` if it isn't there or isn't a `T`.
+    fn get_mut<T: Widget>(&mut self, id: impl IntoId) -> Option<&mut T> {
+        self.find_by_id_mut(id)?.downcast_mut()
+    }
+}
+
+impl<W: Widget> Lookup for W {
+    fn find_by_id(&self, id: impl IntoId) -> Option<&dyn Widget> {
+        find_by_id(self, id)
+    }
+
+    fn find_by_id_mut(&mut self, id: impl IntoId) -> Option<&mut dyn Widget> {
+        find_by_id_mut(self, id)
+    }
+}
 
-    ///* Handle inputs.
-    fn handle_event(&mut self);
+impl Lookup for dyn Widget {
+    fn find_by_id(&self, id: impl IntoId) -> Option<&dyn Widget> {
+        find_by_id(self, id)
+    }
 
-    ///* Trigger the callback `on_click`.
-    fn trigger_callback(&mut self, clicked_widget: Option<Widget>) -> Option<Widget>;
+    fn find_by_id_mut(&mut self, id: impl IntoId) -> Option<&mut dyn Widget> {
+        find_by_id_mut(self, id)
+    }
 }
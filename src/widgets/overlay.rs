@@ -0,0 +1,204 @@
+use super::{focus_next, AsWidget, IntoId, Rect, Widget};
+use crate::{Buffer, Context, Style};
+use crossterm::event::Event;
+
+/// * Draws layers (a `Dialog` or any Widget) centered over a base Widget.
+/// * While a layer is open it gets every event and the focus, the base doesn't;
+///   the base shows again as soon as the last layer is closed.
+/// * Open layers from a callback with `.open(..)`, a layer closes itself with `ctx.close_overlay()`.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(12, 3)).unwrap();
+/// let mut root = overlay(lable("background"));
+/// root.open_sized(lable("hi").wrap(), 6, 3);
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["   ┌────┐   ", " ba│hi  │nd ", "   └────┘   "]);
+///
+/// root.close();
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["            ", " background ", "            "]);
+/// ```
+pub struct Overlay {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Widget under the layers.
+    pub base: Box<dyn Widget>,
+
+    /// Open layers, the last one on top.
+    layers: Vec<Box<dyn Widget>>,
+
+    /// Size of each layer, from the area when `None`.
+    sizes: Vec<Option<(u16, u16)>>,
+
+    /// Drawn over the base while a layer is open.
+    backdrop_style: Style,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Overlay {
+    /// * Construct new overlay without layers over `base`.
+    pub fn new(base: impl AsWidget) -> Self {
+        Self {
+            id: None,
+            base: base.as_widget(),
+            layers: Vec::new(),
+            sizes: Vec::new(),
+            backdrop_style: Style::default(),
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set the style drawn over the base while a layer is open, `.dim()` for example.
+    pub fn backdrop_style(mut self, style: Style) -> Self {
+        self.backdrop_style = style;
+        self
+    }
+
+    /// * Open `widget` on top, half the width (at least 30 columns)
+    ///   and its preferred height (or half the height).
+    pub fn open(&mut self, widget: impl AsWidget) {
+        self.push(widget.as_widget(), None);
+    }
+
+    /// * Open `widget` on top, `width` x `height` cells.
+    pub fn open_sized(&mut self, widget: impl AsWidget, width: u16, height: u16) {
+        self.push(widget.as_widget(), Some((width, height)));
+    }
+
+    /// Close the top layer.
+    pub fn close(&mut self) {
+        self.layers.pop();
+        self.sizes.pop();
+    }
+
+    /// * Check if a layer is open.
+    pub fn is_open(&self) -> bool {
+        !self.layers.is_empty()
+    }
+
+    /// Top layer.
+    pub fn top(&mut self) -> Option<&mut dyn Widget> {
+        self.layers
+            .last_mut()
+            .map(|w| w.as_mut() as &mut dyn Widget)
+    }
+
+    /// * Add a layer on top and focus its first focusable Widget.
+    fn push(&mut self, mut widget: Box<dyn Widget>, size: Option<(u16, u16)>) {
+        focus_next(widget.as_mut(), false);
+        self.layers.push(widget);
+        self.sizes.push(size);
+    }
+
+    /// * Area of `layer` centered in `rect`.
+    fn area(rect: Rect, layer: &dyn Widget, size: Option<(u16, u16)>) -> Rect {
+        let (w, h) = match size {
+            Some((w, h)) => (w as f32, h as f32),
+            None => {
+                let w = (rect.w * 0.5).max(30.0).min(rect.w).floor();
+                let h = layer.preferred_height(w).unwrap_or(rect.h * 0.5);
+                (w, h.ceil())
+            }
+        };
+        let (w, h) = (w.min(rect.w), h.min(rect.h));
+        Rect::new(
+            rect.x + ((rect.w - w) * 0.5).floor(),
+            rect.y + ((rect.h - h) * 0.5).floor(),
+            w,
+            h,
+        )
+    }
+}
+
+impl Widget for Overlay {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        self.base.render(rect, buf);
+        if self.layers.is_empty() {
+            return;
+        }
+
+        // the base's cursor is covered.
+        buf.hide_cursor();
+        buf.set_style(rect, self.backdrop_style);
+        for (layer, size) in self.layers.iter_mut().zip(&self.sizes) {
+            let area = Self::area(rect, layer.as_ref(), *size);
+            buf.clear(area);
+            if !area.is_empty() {
+                layer.render(area, buf);
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        self.base.preferred_height(width)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let Some(top) = self.layers.last_mut() else {
+            return self.base.handle_event(event, ctx);
+        };
+
+        // everything goes to the top layer, even when unused.
+        let mut inner = Context::new();
+        top.handle_event(event, &mut inner);
+        if inner.take_close() {
+            self.close();
+        }
+        ctx.append(inner);
+        true
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        match self.layers.len() {
+            0 => std::slice::from_ref(&self.base),
+            n => &self.layers[n - 1..],
+        }
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        match self.layers.len() {
+            0 => std::slice::from_mut(&mut self.base),
+            n => &mut self.layers[n - 1..],
+        }
+    }
+
+    fn all_children(&self) -> Vec<&dyn Widget> {
+        std::iter::once(&self.base)
+            .chain(&self.layers)
+            .map(|w| w.as_ref())
+            .collect()
+    }
+
+    fn all_children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        std::iter::once(&mut self.base)
+            .chain(&mut self.layers)
+            .map(|w| w.as_mut())
+            .collect()
+    }
+}
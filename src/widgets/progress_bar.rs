@@ -0,0 +1,229 @@
+use super::{IntoId, Rect, Widget};
+use crate::{text_width, truncate, Alignment, Block, Buffer, Style, VAlignment};
+
+/// Partial cells, by eighths.
+const EIGHTHS: [&str; 9] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// * One row progress bar, filled left to right with a precision of 1/8 cell.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+/// let mut bar = progress_bar().ratio(0.45);
+/// terminal.draw(|rect, buf| bar.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["████▌     "]);
+/// ```
+pub struct ProgressBar {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Progress, from 0.0 to 1.0.
+    ratio: f64,
+
+    /// Text drawn over the bar.
+    label: Option<String>,
+
+    /// Draw the percentage when there's no label.
+    show_percent: bool,
+
+    /// Style of the filled part, drawn over the widget style.
+    filled_style: Style,
+
+    /// Style of the empty part, drawn over the widget style.
+    empty_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl ProgressBar {
+    /// * Construct new empty progress bar.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            ratio: 0.0,
+            label: None,
+            show_percent: false,
+            filled_style: Style::default(),
+            empty_style: Style::default(),
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Set the progress, kept between 0.0 and 1.0.
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    /// Set the text drawn over the bar.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Draw the percentage over the bar when there's no label.
+    pub fn show_percent(mut self) -> Self {
+        self.show_percent = true;
+        self
+    }
+
+    /// * Set the style of the filled part.
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// * Set the style of the empty part.
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Progress, from 0.0 to 1.0.
+    pub fn get_ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Set the progress, kept between 0.0 and 1.0.
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = match ratio.is_nan() {
+            true => 0.0,
+            false => ratio.clamp(0.0, 1.0),
+        };
+    }
+
+    /// Set the text drawn over the bar.
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+}
+
+/// * Draw a bar filled to `ratio` on every row of `rect`, with `label` centered on the middle row.
+pub(super) fn draw_bar(
+    rect: Rect,
+    ratio: f64,
+    label: Option<&str>,
+    filled_style: Style,
+    empty_style: Style,
+    buf: &mut Buffer,
+) {
+    let (width, height) = (rect.w.max(0.0) as usize, rect.h.max(0.0) as usize);
+    let eighths = (ratio * width as f64 * 8.0).round() as usize;
+    let (full, partial) = (eighths / 8, eighths % 8);
+
+    let mut row = EIGHTHS[8].repeat(full.min(width));
+    if full < width {
+        row += EIGHTHS[partial];
+        row += &" ".repeat(width - full - 1);
+    }
+    let filled = (full + (partial > 0) as usize) as f32;
+    for y in 0..height {
+        let y = rect.y + y as f32;
+        buf.draw((rect.x, y), &row);
+        buf.set_style(Rect::new(rect.x, y, filled.min(rect.w), 1.0), filled_style);
+        buf.set_style(
+            Rect::new(rect.x + filled, y, (rect.w - filled).max(0.0), 1.0),
+            empty_style,
+        );
+    }
+
+    let Some(label) = label else {
+        return;
+    };
+    let label = truncate(label, width);
+    let x = rect.x + Alignment::Center.offset(width, text_width(&label)) as f32;
+    let y = rect.y + VAlignment::Middle.offset(height, 1) as f32;
+    buf.draw((x, y), &label);
+    // readable over the filled part.
+    let over = (rect.x + full as f32 - x).clamp(0.0, text_width(&label) as f32);
+    buf.set_style(Rect::new(x, y, over, 1.0), filled_style.reversed());
+}
+
+impl Widget for ProgressBar {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required, middle row.
+        let height = self.preferred_height(rect.w).unwrap_or(1.0);
+        let y =
+            rect.y + VAlignment::Middle.offset(rect.h.max(0.0) as usize, height as usize) as f32;
+        self.shape = Rect::new(rect.x, y, rect.w, rect.h.min(height));
+        buf.set_style(self.shape, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(self.shape, buf);
+                block.inner(self.shape)
+            }
+            None => self.shape,
+        };
+        let percent = format!("{}%", (self.ratio * 100.0).round());
+        let label = match (&self.label, self.show_percent) {
+            (Some(label), _) => Some(label.as_str()),
+            (None, true) => Some(percent.as_str()),
+            (None, false) => None,
+        };
+        draw_bar(
+            Rect::new(content.x, content.y, content.w, content.h.min(1.0)),
+            self.ratio,
+            label,
+            self.filled_style,
+            self.empty_style,
+            buf,
+        );
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        Some(1.0 + self.block.as_ref().map_or(0.0, |b| b.vertical_space()))
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,249 @@
+use super::{list::navigate, Id, IntoId, ListCallback, ListMessage, Rect, Widget};
+use crate::{truncate, Buffer, Context, Style, VAlignment};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::rc::Rc;
+
+/// * Options, one per row, only one of them is selected.
+/// * A click selects an option; when focused `Up`/`Down` move the cursor and `Space` selects.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+/// let mut size = radio_group(vec!["Small", "Medium", "Large"]).select(1);
+/// terminal.draw(|rect, buf| size.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["( ) Small ", "(•) Medium", "( ) Large "]);
+/// assert_eq!(size.selected_option(), Some("Medium"));
+/// ```
+pub struct RadioGroup {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Options to choose from.
+    pub options: Vec<String>,
+
+    /// Selected option.
+    selected: Option<usize>,
+
+    /// Option under the keyboard cursor.
+    cursor: usize,
+
+    /// Widget style.
+    style: Style,
+
+    /// Style of the selected option's mark, drawn over the widget style.
+    selected_style: Style,
+
+    /// Drawn over the cursor row when focused.
+    focus_style: Style,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the selection changed.
+    pub on_change: Option<ListCallback>,
+
+    /// Message sent after the selection changed.
+    pub change_msg: Option<ListMessage>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl RadioGroup {
+    /// * Construct new radio group, nothing selected.
+    pub fn new<T: Into<String>>(options: Vec<T>) -> Self {
+        Self {
+            id: None,
+            options: options.into_iter().map(Into::into).collect(),
+            selected: None,
+            cursor: 0,
+            style: Style::default(),
+            selected_style: Style::default(),
+            focus_style: Style::default().reversed(),
+            focused: false,
+            rid: None,
+            on_change: None,
+            change_msg: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Select the `index`th option.
+    pub fn select(mut self, index: usize) -> Self {
+        self.set_selected(Some(index));
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style of the selected option's mark.
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// * Set the style of the cursor row while the widget has the keyboard focus,
+    ///   reversed by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// * Storing the callback, triggered with the selected option.
+    pub fn on_change(mut self, f: impl Fn(&mut dyn Widget, usize, &str) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// * Send the message made by `f` from the new selection to the `App`. See `App`.
+    pub fn on_change_msg<M: 'static>(mut self, f: impl Fn(usize, &str) -> M + 'static) -> Self {
+        self.change_msg = Some(Rc::new(move |i, option| Box::new(f(i, option))));
+        self
+    }
+
+    /// Selected option index.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selected option text.
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options.get(self.selected?).map(String::as_str)
+    }
+
+    /// * Select the `index`th option (kept inside the group), or nothing,
+    ///   without calling `on_change`.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        self.selected = match self.options.len() {
+            0 => None,
+            len => index.map(|i| i.min(len - 1)),
+        };
+        if let Some(i) = self.selected {
+            self.cursor = i;
+        }
+    }
+
+    /// * Select the `index`th option and queue `on_change` on the related Widget.
+    fn choose(&mut self, index: usize, ctx: &mut Context) {
+        if self.selected == Some(index) {
+            return;
+        }
+        self.set_selected(Some(index));
+        if let (Some(msg), Some(option)) = (&self.change_msg, self.options.get(index)) {
+            ctx.send_any(msg(index, option));
+        }
+        if let (Some(f), Some(option)) = (&self.on_change, self.options.get(index)) {
+            let (f, option) = (Rc::clone(f), option.clone());
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, index, &option)),
+                None => f(self, index, &option),
+            }
+        }
+    }
+}
+
+impl Widget for RadioGroup {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required, rows in the middle.
+        let rows = self.options.len().min(rect.h.max(0.0) as usize);
+        let y = rect.y + VAlignment::Middle.offset(rect.h.max(0.0) as usize, rows) as f32;
+        self.shape = Rect::new(rect.x, y, rect.w, rows as f32);
+        buf.set_style(self.shape, self.style);
+
+        let width = rect.w.max(0.0) as usize;
+        for (i, option) in self.options.iter().take(rows).enumerate() {
+            let row = y + i as f32;
+            let selected = self.selected == Some(i);
+            let mark = if selected { "(•) " } else { "( ) " };
+            buf.draw(
+                (rect.x, row),
+                &truncate(&(mark.to_string() + option), width),
+            );
+            if selected {
+                buf.set_style(
+                    Rect::new(rect.x, row, 3.0_f32.min(rect.w), 1.0),
+                    self.selected_style,
+                );
+            }
+            if self.focused && self.cursor == i {
+                buf.set_style(Rect::new(rect.x, row, rect.w, 1.0), self.focus_style);
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        Some(self.options.len() as f32)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if self.shape.contains(*column, *row) => {
+                let i = (*row as f32 - self.shape.y) as usize;
+                self.cursor = i;
+                self.choose(i, ctx);
+            }
+            Event::Key(key) if self.focused && key.kind != KeyEventKind::Release => {
+                if key.code == KeyCode::Char(' ') {
+                    self.choose(self.cursor, ctx);
+                    return true;
+                }
+                let page = self.shape.h as usize;
+                match navigate(key.code, Some(self.cursor), self.options.len(), page) {
+                    Some(i) => self.cursor = i,
+                    None => return false,
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,323 @@
+use super::{walk, AsWidget, IntoId, Rect, Widget};
+use crate::{Buffer, Context};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEvent, MouseEventKind};
+
+/// Rows scrolled by one mouse wheel step.
+const WHEEL_STEP: u16 = 3;
+
+/// * Shows part of a Widget taller than its area, scrolled vertically.
+/// * The child gets its preferred height (the whole `vbox` for instance) and is clipped
+///   to the ScrollView's Rect.
+/// * Scrolls with the mouse wheel, `Up`/`Down`, `PageUp`/`PageDown`, `Home`/`End`
+///   (keys the child doesn't use, while the keyboard focus is inside), and follows the focus.
+/// * When nothing inside can take the focus, the ScrollView takes it itself, so it can still be
+///   scrolled with the keyboard.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(6, 2)).unwrap();
+/// let mut root = scroll_view(vbox(widgets![lable("1"), lable("2"), lable("3")])).hide_scrollbar();
+/// root.scroll_to(1);
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec![" 2    ", " 3    "]);
+/// ```
+///
+/// Keys only scroll while the focus is inside, the List below keeps its arrows:
+///
+/// ```
+/// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::functions::*;
+///
+/// let mut root = vbox(widgets![
+///     scroll_view(vbox(widgets![lable("1"), lable("2"), lable("3"), lable("4")])),
+///     list(vec!["a", "b", "c"])
+/// ]);
+/// let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+/// let mut terminal = Terminal::new(TestBackend::new(6, 4)).unwrap();
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// // the ScrollView first (nothing inside takes the focus), then the List.
+/// root.dispatch(&key(KeyCode::Tab)).unwrap();
+/// root.dispatch(&key(KeyCode::Down)).unwrap();
+/// root.dispatch(&key(KeyCode::Tab)).unwrap();
+/// root.dispatch(&key(KeyCode::Down)).unwrap();
+/// root.dispatch(&key(KeyCode::Down)).unwrap();
+///
+/// assert_eq!(root.widgets[0].downcast_ref::<ScrollView>().unwrap().offset(), 1);
+/// assert_eq!(root.widgets[1].downcast_ref::<List>().unwrap().selected(), Some(1));
+/// ```
+pub struct ScrollView {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Scrolled Widget.
+    pub child: Box<dyn Widget>,
+
+    /// First visible row of the child.
+    offset: u16,
+
+    /// Height of the child, from the last render.
+    content_height: u16,
+
+    /// Draw a scrollbar on the right side.
+    scrollbar: bool,
+
+    /// Shape of the focused Widget last time, to scroll only when the focus moves.
+    focus: Option<Rect>,
+
+    /// Keyboard focus, only taken when nothing inside can take it.
+    focused: bool,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl ScrollView {
+    /// * Construct new scroll view around `child`.
+    pub fn new(child: impl AsWidget) -> Self {
+        Self {
+            id: None,
+            child: child.as_widget(),
+            offset: 0,
+            content_height: 0,
+            scrollbar: true,
+            focus: None,
+            focused: false,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// Don't draw the scrollbar.
+    pub fn hide_scrollbar(mut self) -> Self {
+        self.scrollbar = false;
+        self
+    }
+
+    /// First visible row of the child.
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// * Scroll so `row` of the child is the first visible row.
+    /// * Kept inside the child on the next render.
+    pub fn scroll_to(&mut self, row: u16) {
+        self.offset = row;
+    }
+
+    /// Scroll `rows` up.
+    pub fn scroll_up(&mut self, rows: u16) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Scroll `rows` down.
+    pub fn scroll_down(&mut self, rows: u16) {
+        self.offset = self.offset.saturating_add(rows).min(self.max_offset());
+    }
+
+    /// * Last possible offset.
+    fn max_offset(&self) -> u16 {
+        self.content_height
+            .saturating_sub(self.viewport(self.shape).h as u16)
+    }
+
+    /// * Area the child is shown in, without the scrollbar.
+    fn viewport(&self, rect: Rect) -> Rect {
+        let scrollbar = self.scrollbar && self.content_height as f32 > rect.h;
+        match scrollbar {
+            true => Rect::new(rect.x, rect.y, (rect.w - 1.0).max(0.0), rect.h),
+            false => rect,
+        }
+    }
+
+    /// * Scroll so the focused Widget is visible, when the focus moved.
+    fn follow_focus(&mut self, height: u16) {
+        let mut focus = None;
+        walk(self.child.as_ref(), &mut |w| {
+            if w.is_focused() {
+                focus = Some(*w.shape());
+            }
+        });
+        if focus == self.focus {
+            return;
+        }
+        self.focus = focus;
+
+        if let Some(shape) = focus {
+            let (top, bottom) = (shape.y.max(0.0) as u16, (shape.y + shape.h).max(0.0) as u16);
+            if top < self.offset {
+                self.offset = top;
+            } else if bottom > self.offset + height {
+                self.offset = bottom.saturating_sub(height).min(top);
+            }
+        }
+    }
+
+    /// * Check if the keyboard focus is on the view or inside it.
+    fn has_focus(&self) -> bool {
+        let mut focused = self.focused;
+        walk(self.child.as_ref(), &mut |w| focused |= w.is_focused());
+        focused
+    }
+
+    /// * Draw the scrollbar in the last column of `rect`.
+    fn render_scrollbar(&self, rect: Rect, buf: &mut Buffer) {
+        let (height, content) = (rect.h as u16, self.content_height);
+        let x = rect.x + rect.w - 1.0;
+        let thumb = (height as u32 * height as u32 / content.max(1) as u32).max(1) as u16;
+        let top = match self.max_offset() {
+            0 => 0,
+            max => (self.offset as u32 * height.saturating_sub(thumb) as u32 / max as u32) as u16,
+        };
+        for i in 0..height {
+            let symbol = if (top..top + thumb).contains(&i) {
+                "█"
+            } else {
+                "│"
+            };
+            buf.draw((x, rect.y + i as f32), symbol);
+        }
+    }
+
+    /// * Mouse event moved into the child's coordinates, `None` outside the viewport.
+    fn translate(&self, mouse: &MouseEvent) -> Option<Event> {
+        let view = self.viewport(self.shape);
+        if !view.contains(mouse.column, mouse.row) {
+            return None;
+        }
+        let mut mouse = *mouse;
+        mouse.column -= view.x as u16;
+        mouse.row = mouse.row - view.y as u16 + self.offset;
+        Some(Event::Mouse(mouse))
+    }
+}
+
+impl Widget for ScrollView {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+
+        // the child is laid out as if the terminal was as tall as it wants.
+        let measure = |w: f32| self.child.preferred_height(w.max(0.0)).unwrap_or(rect.h);
+        let mut height = measure(rect.w);
+        if self.scrollbar && height > rect.h {
+            // narrower with the scrollbar.
+            height = measure(rect.w - 1.0);
+        }
+        self.content_height = height.max(0.0).ceil() as u16;
+        let view = self.viewport(rect);
+        let mut content = Buffer::empty(view.w as u16, self.content_height);
+        self.child.render(content.area(), &mut content);
+
+        self.follow_focus(view.h as u16);
+        self.offset = self.offset.min(self.max_offset());
+
+        for y in 0..view.h as u16 {
+            for x in 0..view.w as u16 {
+                let (Some(src), Some(dst)) = (
+                    content.get(x, y + self.offset),
+                    buf.get_mut(view.x as u16 + x, view.y as u16 + y),
+                ) else {
+                    continue;
+                };
+                dst.set_symbol(&src.symbol).set_style(src.style);
+            }
+        }
+        if let Some((x, y)) = content.cursor() {
+            if (self.offset..self.offset + view.h as u16).contains(&y) {
+                buf.set_cursor(view.x + x as f32, view.y + (y - self.offset) as f32);
+            }
+        }
+
+        if view.w < rect.w {
+            self.render_scrollbar(rect, buf);
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        match event {
+            Event::Mouse(mouse) => {
+                let Some(inner) = self.translate(mouse) else {
+                    return false;
+                };
+                if self.child.handle_event(&inner, ctx) {
+                    return true;
+                }
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => self.scroll_up(WHEEL_STEP),
+                    MouseEventKind::ScrollDown => self.scroll_down(WHEEL_STEP),
+                    _ => return false,
+                }
+                true
+            }
+            Event::Key(key) => {
+                if self.child.handle_event(event, ctx) {
+                    return true;
+                }
+                if key.kind == KeyEventKind::Release || !self.has_focus() {
+                    return false;
+                }
+                let page = (self.viewport(self.shape).h as u16)
+                    .saturating_sub(1)
+                    .max(1);
+                match key.code {
+                    KeyCode::Up => self.scroll_up(1),
+                    KeyCode::Down => self.scroll_down(1),
+                    KeyCode::PageUp => self.scroll_up(page),
+                    KeyCode::PageDown => self.scroll_down(page),
+                    KeyCode::Home => self.scroll_to(0),
+                    KeyCode::End => self.scroll_to(self.max_offset()),
+                    _ => return false,
+                }
+                true
+            }
+            _ => self.child.handle_event(event, ctx),
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        let mut inner = false;
+        walk(self.child.as_ref(), &mut |w| inner |= w.focusable());
+        !inner
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused && self.focusable();
+    }
+
+    fn restore(&mut self, old: &dyn Widget) {
+        if let Some(old) = old.downcast_ref::<Self>() {
+            self.offset = old.offset;
+        }
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        std::slice::from_ref(&self.child)
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        std::slice::from_mut(&mut self.child)
+    }
+}
@@ -0,0 +1,155 @@
+use super::{IntoId, Rect, Widget};
+use crate::{Block, Buffer, Style};
+
+/// Bottom-up partial cells, by eighths.
+pub(super) const BARS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// * Column of `height` rows filled to `eighths` from the bottom, top row first.
+pub(super) fn column(eighths: usize, height: usize) -> Vec<&'static str> {
+    (0..height)
+        .rev()
+        .map(|row| BARS[eighths.saturating_sub(row * 8).min(8)])
+        .collect()
+}
+
+/// * Eighths of `height` rows filled by `value` when `max` fills them all, rounded.
+/// * Computed in `u128`, so any `u64` fits.
+///
+/// ```
+/// use simple_tui::eighths;
+///
+/// assert_eq!(eighths(3, 6, 2), 8);
+/// assert_eq!(eighths(12, 6, 2), 16);
+/// assert_eq!(eighths(0, 0, 3), 0);
+/// assert_eq!(eighths(u64::MAX / 2, u64::MAX, 1), 4);
+/// assert_eq!(eighths(u64::MAX, u64::MAX, 1000), 8000);
+/// ```
+pub fn eighths(value: u64, max: u64, height: usize) -> usize {
+    let (value, max) = (value.min(max) as u128, max.max(1) as u128);
+    ((value * height as u128 * 8 + max / 2) / max) as usize
+}
+
+/// * Inline chart of recent values, one column per value.
+/// * Shows the last values that fit, scaled to the max value (or `.max(..)`).
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(6, 2)).unwrap();
+/// let mut cpu = sparkline(vec![0, 1, 2, 4, 8, 16, 12]);
+/// terminal.draw(|rect, buf| cpu.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["    █▄", "▁▂▄███"]);
+/// ```
+pub struct Sparkline {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Values, oldest first.
+    pub data: Vec<u64>,
+
+    /// Value of a full column, the max of the data by default.
+    max: Option<u64>,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Sparkline {
+    /// * Construct new sparkline.
+    pub fn new(data: Vec<u64>) -> Self {
+        Self {
+            id: None,
+            data,
+            max: None,
+            style: Style::default(),
+            block: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set the value of a full column, instead of the max of the data.
+    pub fn max(mut self, max: u64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// * Append a value, dropping the oldest ones beyond `capacity`.
+    pub fn push(&mut self, value: u64, capacity: usize) {
+        self.data.push(value);
+        let extra = self.data.len().saturating_sub(capacity);
+        self.data.drain(..extra);
+    }
+}
+
+impl Widget for Sparkline {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+
+        let content = match &self.block {
+            Some(block) => {
+                block.draw(rect, buf);
+                block.inner(rect)
+            }
+            None => rect,
+        };
+        let (width, height) = (content.w.max(0.0) as usize, content.h.max(0.0) as usize);
+        let visible = &self.data[self.data.len().saturating_sub(width)..];
+        let max = self
+            .max
+            .unwrap_or_else(|| visible.iter().copied().max().unwrap_or(0))
+            .max(1);
+
+        for (x, value) in visible.iter().enumerate() {
+            for (y, symbol) in column(eighths(*value, max, height), height)
+                .into_iter()
+                .enumerate()
+            {
+                buf.draw((content.x + x as f32, content.y + y as f32), symbol);
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+}
@@ -0,0 +1,93 @@
+use super::{dispatch_with, find_by_id_mut, Context, IntoId, LookupError, Widget};
+use crate::Runner;
+use crossterm::event::Event;
+use std::io;
+
+/// * Widget id and how to update that Widget from the state.
+type Binding<S> = (usize, Box<dyn Fn(&S, &mut dyn Widget)>);
+
+/// * Application state kept outside the Widget tree.
+/// * Callbacks change it (`Button::on_click_state`, `ctx.update(..)`), and bound Widgets
+///   are updated from it before every render, so one change can reach many Widgets.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::functions::*;
+///
+/// let mut root = vbox(widgets![
+///     lable("").set_id(1),
+///     lable("").set_id(2),
+///     button("+1").on_click_state(|n: &mut i32| *n += 1)
+/// ]);
+/// let mut store = Store::new(0)
+///     .bind(1, |n: &i32, l: &mut Lable| l.text = format!("Count: {n}"))
+///     .bind(2, |n: &i32, l: &mut Lable| l.text = format!("Even: {}", n % 2 == 0));
+///
+/// root.dispatch(&Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE))).unwrap();
+/// store.dispatch(&mut root, &Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))).unwrap();
+/// store.sync(&mut root);
+///
+/// assert_eq!(store.state, 1);
+/// assert_eq!(root.widgets[0].downcast_ref::<Lable>().unwrap().text, "Count: 1");
+/// assert_eq!(root.widgets[1].downcast_ref::<Lable>().unwrap().text, "Even: false");
+/// ```
+pub struct Store<S> {
+    /// Application state.
+    pub state: S,
+
+    /// Widgets updated from the state.
+    bindings: Vec<Binding<S>>,
+}
+
+impl<S: 'static> Store<S> {
+    /// * Construct new store around `state`, nothing bound.
+    pub fn new(state: S) -> Self {
+        Self {
+            state,
+            bindings: Vec::new(),
+        }
+    }
+
+    /// * Update the Widget with id `id` from the state before every render.
+    /// * Ignored when that Widget isn't a `W`, or isn't in the tree.
+    pub fn bind<W: Widget>(mut self, id: impl IntoId, f: impl Fn(&S, &mut W) + 'static) -> Self {
+        self.bindings.push((
+            id.into_id().value(),
+            Box::new(move |state, w| {
+                if let Some(w) = w.downcast_mut::<W>() {
+                    f(state, w)
+                }
+            }),
+        ));
+        self
+    }
+
+    /// * Update the bound Widgets under `root` from the state.
+    pub fn sync(&self, root: &mut dyn Widget) {
+        for (id, f) in &self.bindings {
+            if let Some(w) = find_by_id_mut(root, *id) {
+                f(&self.state, w);
+            }
+        }
+    }
+
+    /// * Send one input event through the tree under `root`,
+    ///   then change the state and run the callbacks.
+    pub fn dispatch(&mut self, root: &mut dyn Widget, event: &Event) -> Result<(), LookupError> {
+        dispatch_with(root, event, &mut self.state)
+    }
+
+    /// * Change the state, then run the callbacks of `ctx` on the Widgets under `root`.
+    /// * Used for what ticks and timers want to do, see `Runner::every`.
+    pub fn apply(&mut self, root: &mut dyn Widget, ctx: Context) -> Result<(), LookupError> {
+        ctx.apply_with(root, &mut self.state)
+    }
+
+    /// #####  Start rendering `root` on screen until `Esc` is pressed.
+    /// * Use `Runner::run_with` to choose the quit key or the backend.
+    pub fn run(&mut self, root: &mut dyn Widget) -> io::Result<()> {
+        Runner::new()?.run_with(root, self)
+    }
+}
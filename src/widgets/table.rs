@@ -0,0 +1,522 @@
+use super::{list::navigate, Id, IntoId, Rect, Widget};
+use crate::{
+    split, text_width, truncate, Alignment, Block, Buffer, Constraint, Context, Direction, Style,
+};
+use crossterm::event::{Event, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::{cmp::Ordering, rc::Rc};
+
+/// * Callback triggered by `on_select`, receives the row index and cells.
+pub type RowCallback = Rc<dyn Fn(&mut dyn Widget, usize, &[String])>;
+
+/// * Compares two cells of the sorted column, receives the column index.
+pub type SortCallback = Rc<dyn Fn(usize, &str, &str) -> Ordering>;
+
+/// * Rows of cells under a header, with aligned columns.
+/// * `Up`/`Down`, `PageUp`/`PageDown`, `Home`/`End` or a click select a row.
+/// * With `on_sort` or `sortable`, clicking a header sorts the rows by that column,
+///   clicking it again reverses the order.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(16, 3)).unwrap();
+/// let mut jobs = table(vec!["Job", "Time"])
+///     .widths(vec![Constraint::Fill(1), Constraint::Length(5)])
+///     .alignments(vec![Alignment::Left, Alignment::Right])
+///     .row(vec!["build", "12s"])
+///     .row(vec!["test", "1m3s"])
+///     .sortable();
+/// jobs.sort_by_column(0, true);
+/// terminal.draw(|rect, buf| jobs.render(rect, buf)).unwrap();
+///
+/// assert_eq!(
+///     terminal.backend().lines(),
+///     vec!["Job ▲       Time", "build        12s", "test        1m3s"]
+/// );
+/// ```
+///
+/// The sort order and the selection survive the view being built again by a `Program`:
+///
+/// ```
+/// use simple_tui::crossterm::event::*;
+/// use simple_tui::functions::*;
+///
+/// struct Files;
+///
+/// impl App for Files {
+///     type Msg = ();
+///
+///     fn update(&mut self, _: ()) {}
+///
+///     fn view(&self) -> Box<dyn Widget> {
+///         let files = table(vec!["Name"]).row(vec!["b"]).row(vec!["c"]).row(vec!["a"]);
+///         files.sortable().as_widget()
+///     }
+/// }
+///
+/// let mut program = Program::new(Files);
+/// let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+/// terminal.draw(|rect, buf| program.render(rect, buf)).unwrap();
+/// let header = Event::Mouse(MouseEvent {
+///     kind: MouseEventKind::Down(MouseButton::Left),
+///     column: 0,
+///     row: 0,
+///     modifiers: KeyModifiers::NONE,
+/// });
+/// let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+/// program.dispatch(&header).unwrap(); // ascending
+/// program.dispatch(&header).unwrap(); // descending
+/// program.dispatch(&key(KeyCode::Tab)).unwrap();
+/// program.dispatch(&key(KeyCode::Down)).unwrap();
+/// program.dispatch(&key(KeyCode::Down)).unwrap();
+///
+/// // the new view comes unsorted.
+/// program.send(());
+///
+/// let files = program.root().downcast_ref::<Table>().unwrap();
+/// assert_eq!(files.sort_column(), Some((0, false)));
+/// assert_eq!(files.rows, [["c"], ["b"], ["a"]]);
+/// assert_eq!(files.selected(), Some(1));
+/// ```
+pub struct Table {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Column titles.
+    pub header: Vec<String>,
+
+    /// Cells, row by row.
+    pub rows: Vec<Vec<String>>,
+
+    /// Column widths, `Constraint::Fill(1)` when missing.
+    widths: Vec<Constraint>,
+
+    /// Column alignments, `Alignment::Left` when missing.
+    alignments: Vec<Alignment>,
+
+    /// Empty columns between two columns.
+    column_spacing: u16,
+
+    /// Selected row.
+    selected: Option<usize>,
+
+    /// First visible row.
+    offset: usize,
+
+    /// Scroll to the selected row on the next render.
+    follow: bool,
+
+    /// Sorted column and order (ascending when true).
+    sort: Option<(usize, bool)>,
+
+    /// Header style, drawn over the widget style.
+    header_style: Style,
+
+    /// Style of the selected row, drawn over the widget style.
+    highlight_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the selection changed.
+    pub on_select: Option<RowCallback>,
+
+    /// Cell comparison, sorting is disabled without it.
+    pub on_sort: Option<SortCallback>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Table {
+    /// * Construct new table without rows.
+    pub fn new<T: Into<String>>(header: Vec<T>) -> Self {
+        Self {
+            id: None,
+            header: header.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+            widths: Vec::new(),
+            alignments: Vec::new(),
+            column_spacing: 1,
+            selected: None,
+            offset: 0,
+            follow: false,
+            sort: None,
+            header_style: Style::default().bold(),
+            highlight_style: Style::default().reversed(),
+            style: Style::default(),
+            block: None,
+            focused: false,
+            rid: None,
+            on_select: None,
+            on_sort: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Add a row.
+    pub fn row<T: Into<String>>(mut self, cells: Vec<T>) -> Self {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set all the rows.
+    pub fn rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// * Set column widths, in the same order as the header.
+    /// * Columns without a constraint get `Constraint::Fill(1)`.
+    pub fn widths(mut self, widths: Vec<Constraint>) -> Self {
+        self.widths = widths;
+        self
+    }
+
+    /// * Set column alignments, in the same order as the header.
+    /// * Columns without an alignment are aligned left.
+    pub fn alignments(mut self, alignments: Vec<Alignment>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
+    /// Set empty columns between two columns, 1 by default.
+    pub fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Select the `index`th row.
+    pub fn select(mut self, index: usize) -> Self {
+        self.set_selected(Some(index));
+        self
+    }
+
+    /// * Set the style of the header, bold by default.
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// * Set the style of the selected row, reversed by default.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// * Storing the callback, triggered with the new selection.
+    pub fn on_select(mut self, f: impl Fn(&mut dyn Widget, usize, &[String]) + 'static) -> Self {
+        self.on_select = Some(Rc::new(f));
+        self
+    }
+
+    /// * Allow sorting by clicking the header, cells are compared with `f`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use simple_tui::functions::*;
+    ///
+    /// // numbers in the second column.
+    /// table(vec!["Name", "Size"]).on_sort(|column, a, b| match column {
+    ///     1 => a.parse::<u64>().unwrap_or(0).cmp(&b.parse().unwrap_or(0)),
+    ///     _ => a.cmp(b),
+    /// });
+    /// ```
+    pub fn on_sort(mut self, f: impl Fn(usize, &str, &str) -> Ordering + 'static) -> Self {
+        self.on_sort = Some(Rc::new(f));
+        self
+    }
+
+    /// * Allow sorting by clicking the header, numbers are compared as numbers,
+    ///   everything else as text.
+    pub fn sortable(self) -> Self {
+        self.on_sort(|_, a, b| match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.total_cmp(&b),
+            _ => a.cmp(b),
+        })
+    }
+
+    /// Selected row index.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selected row cells.
+    pub fn selected_row(&self) -> Option<&[String]> {
+        self.rows.get(self.selected?).map(Vec::as_slice)
+    }
+
+    /// Sorted column and order (ascending when true).
+    pub fn sort_column(&self) -> Option<(usize, bool)> {
+        self.sort
+    }
+
+    /// * Select the `index`th row (kept inside the table), or nothing.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        self.selected = match self.rows.len() {
+            0 => None,
+            len => index.map(|i| i.min(len - 1)),
+        };
+        self.follow = true;
+    }
+
+    /// * Sort the rows by `column` with the `on_sort` callback, the selected row stays selected.
+    /// * Does nothing when sorting isn't enabled.
+    pub fn sort_by_column(&mut self, column: usize, ascending: bool) {
+        let Some(f) = self.on_sort.clone() else {
+            return;
+        };
+        let cell = |row: &Vec<String>| row.get(column).cloned().unwrap_or_default();
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by(|&a, &b| {
+            let ordering = f(column, &cell(&self.rows[a]), &cell(&self.rows[b]));
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        self.selected = self
+            .selected
+            .and_then(|s| order.iter().position(|&i| i == s));
+        let mut rows: Vec<Option<Vec<String>>> = self.rows.drain(..).map(Some).collect();
+        self.rows = order.iter().filter_map(|&i| rows[i].take()).collect();
+        self.sort = Some((column, ascending));
+        self.follow = true;
+    }
+
+    /// * Area of the header and rows inside the shape.
+    fn content(&self) -> Rect {
+        self.block
+            .as_ref()
+            .map_or(self.shape, |b| b.inner(self.shape))
+    }
+
+    /// * Area of each column inside `rect`.
+    pub fn columns(&self, rect: Rect) -> Vec<Rect> {
+        let n = self.header.len();
+        let spacing = self.column_spacing as f32;
+        let space = spacing * n.saturating_sub(1) as f32;
+        let constraints: Vec<Constraint> = (0..n)
+            .map(|i| self.widths.get(i).copied().unwrap_or(Constraint::Fill(1)))
+            .collect();
+        let area = Rect::new(rect.x, rect.y, (rect.w - space).max(0.0), rect.h);
+        split(area, Direction::Horizontal, &constraints)
+            .into_iter()
+            .enumerate()
+            .map(|(i, r)| Rect::new(r.x + spacing * i as f32, r.y, r.w, r.h))
+            .collect()
+    }
+
+    /// * Draw one row of cells.
+    fn render_row(&self, cells: &[String], columns: &[Rect], y: f32, buf: &mut Buffer) {
+        for (i, column) in columns.iter().enumerate() {
+            let Some(cell) = cells.get(i) else {
+                break;
+            };
+            let available = column.w.max(0.0) as usize;
+            let text = truncate(cell, available);
+            let alignment = self.alignments.get(i).copied().unwrap_or_default();
+            let x = alignment.offset(available, text_width(&text));
+            buf.draw((column.x + x as f32, y), &text);
+        }
+    }
+
+    /// * Queue the `on_select` callback on the related Widget.
+    fn trigger(&mut self, ctx: &mut Context) {
+        let (Some(f), Some(i)) = (&self.on_select, self.selected) else {
+            return;
+        };
+        let Some(row) = self.rows.get(i) else {
+            return;
+        };
+        let (f, row) = (Rc::clone(f), row.clone());
+        match self.rid.clone() {
+            Some(rid) => ctx.trigger(rid, move |w| f(w, i, &row)),
+            None => f(self, i, &row),
+        }
+    }
+}
+
+impl Widget for Table {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+        if let Some(block) = &self.block {
+            block.draw(rect, buf);
+        }
+
+        let content = self.content();
+        if content.is_empty() {
+            return;
+        }
+        let columns = self.columns(content);
+
+        // header, with the sort order.
+        let header: Vec<String> = self
+            .header
+            .iter()
+            .enumerate()
+            .map(|(i, title)| match self.sort {
+                Some((column, true)) if column == i => title.clone() + " ▲",
+                Some((column, false)) if column == i => title.clone() + " ▼",
+                _ => title.clone(),
+            })
+            .collect();
+        self.render_row(&header, &columns, content.y, buf);
+        buf.set_style(
+            Rect::new(content.x, content.y, content.w, 1.0),
+            self.header_style,
+        );
+
+        let height = (content.h - 1.0).max(0.0) as usize;
+        if self.follow {
+            if let Some(i) = self.selected {
+                if i < self.offset {
+                    self.offset = i;
+                } else if i >= self.offset + height {
+                    self.offset = i + 1 - height.min(i + 1);
+                }
+            }
+            self.follow = false;
+        }
+        self.offset = self.offset.min(self.rows.len().saturating_sub(height));
+
+        for (row, i) in (self.offset..self.rows.len()).take(height).enumerate() {
+            let y = content.y + 1.0 + row as f32;
+            self.render_row(&self.rows[i], &columns, y, buf);
+            if self.selected == Some(i) {
+                buf.set_style(
+                    Rect::new(content.x, y, content.w, 1.0),
+                    self.highlight_style,
+                );
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        let space = self.block.as_ref().map_or(0.0, |b| b.vertical_space());
+        Some(self.rows.len() as f32 + 1.0 + space)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let before = self.selected;
+        let content = self.content();
+        match event {
+            Event::Key(key) if self.focused && key.kind != KeyEventKind::Release => {
+                let page = (content.h as usize).saturating_sub(2).max(1);
+                let Some(next) = navigate(key.code, self.selected, self.rows.len(), page) else {
+                    return false;
+                };
+                self.set_selected(Some(next));
+            }
+            Event::Mouse(MouseEvent {
+                kind, column, row, ..
+            }) if content.contains(*column, *row) => match kind {
+                MouseEventKind::Down(MouseButton::Left) if *row as f32 == content.y => {
+                    // header: sort by the clicked column.
+                    let clicked = self
+                        .columns(content)
+                        .iter()
+                        .position(|c| c.contains(*column, *row));
+                    if let (Some(i), Some(_)) = (clicked, &self.on_sort) {
+                        let ascending = self.sort != Some((i, true));
+                        self.sort_by_column(i, ascending);
+                    }
+                    return true;
+                }
+                MouseEventKind::Down(MouseButton::Left) => {
+                    let i = self.offset + (*row as f32 - content.y - 1.0) as usize;
+                    if i < self.rows.len() {
+                        self.set_selected(Some(i));
+                    }
+                }
+                MouseEventKind::ScrollUp => self.offset = self.offset.saturating_sub(1),
+                MouseEventKind::ScrollDown => self.offset += 1,
+                _ => return false,
+            },
+            _ => return false,
+        }
+
+        if self.selected != before {
+            self.trigger(ctx);
+        }
+        true
+    }
+
+    fn restore(&mut self, old: &dyn Widget) {
+        if let Some(old) = old.downcast_ref::<Self>() {
+            if let Some((column, ascending)) = old.sort {
+                self.sort_by_column(column, ascending);
+            }
+            self.set_selected(old.selected);
+            self.offset = old.offset;
+            self.follow = old.follow;
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
@@ -0,0 +1,357 @@
+use super::{walk_mut, AsWidget, Id, IntoId, ListCallback, Rect, Widget};
+use crate::{text_width, truncate, Block, Buffer, Context, Style};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use std::rc::Rc;
+
+/// * Pages with a tab bar on top, only the active page is shown.
+/// * A click on a title, or `Left`/`Right`/`Home`/`End` when the tab bar is focused, switch pages.
+/// * Only the active page gets events and is one of the `children`,
+///   so hidden pages don't take the focus. Callbacks from the active page still reach them.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(14, 2)).unwrap();
+/// let mut root = tabs()
+///     .tab("Home", lable("Welcome"))
+///     .tab("Logs", lable("Nothing yet"))
+///     .select(1);
+/// terminal.draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec![" Home │ Logs  ", " Nothing yet  "]);
+/// assert_eq!(root.selected_title(), Some("Logs"));
+/// ```
+pub struct Tabs {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Titles, one per page.
+    pub titles: Vec<String>,
+
+    /// Pages, one per title.
+    pub pages: Vec<Box<dyn Widget>>,
+
+    /// Active page.
+    selected: usize,
+
+    /// Between the titles.
+    divider: String,
+
+    /// Style of the active title, drawn over the widget style.
+    highlight_style: Style,
+
+    /// Widget style.
+    style: Style,
+
+    /// Drawn over the active title when the tab bar is focused.
+    focus_style: Style,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Columns of each title in the tab bar, as `(x, width)`.
+    title_columns: Vec<(f32, f32)>,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the active page changed.
+    pub on_change: Option<ListCallback>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl Tabs {
+    /// * Construct new tabs without pages.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            titles: Vec::new(),
+            pages: Vec::new(),
+            selected: 0,
+            divider: "│".into(),
+            highlight_style: Style::default().reversed(),
+            style: Style::default(),
+            focus_style: Style::default().underlined(),
+            block: None,
+            focused: false,
+            title_columns: Vec::new(),
+            rid: None,
+            on_change: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// * Add a page, with its title in the tab bar.
+    pub fn tab(mut self, title: impl Into<String>, page: impl AsWidget) -> Self {
+        self.titles.push(title.into());
+        self.pages.push(page.as_widget());
+        self
+    }
+
+    /// Show the `index`th page.
+    pub fn select(mut self, index: usize) -> Self {
+        self.set_selected(index);
+        self
+    }
+
+    /// * Set the text between the titles, `│` by default.
+    pub fn divider(mut self, divider: impl Into<String>) -> Self {
+        self.divider = divider.into();
+        self
+    }
+
+    /// * Set the style of the active title, reversed by default.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style of the active title while the tab bar has the keyboard focus,
+    ///   underlined by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// Wrap around
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// * Storing the callback, triggered with the new active page.
+    pub fn on_change(mut self, f: impl Fn(&mut dyn Widget, usize, &str) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// Active page index.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Active page title.
+    pub fn selected_title(&self) -> Option<&str> {
+        self.titles.get(self.selected).map(String::as_str)
+    }
+
+    /// * Show the `index`th page (kept inside the pages), without calling `on_change`.
+    /// * Widgets of the page being hidden lose the focus.
+    pub fn set_selected(&mut self, index: usize) {
+        let index = index.min(self.pages.len().saturating_sub(1));
+        if index != self.selected {
+            if let Some(page) = self.pages.get_mut(self.selected) {
+                walk_mut(page.as_mut(), &mut |w| w.set_focus(false));
+            }
+        }
+        self.selected = index;
+    }
+
+    /// * Show the `index`th page and queue `on_change` on the related Widget.
+    fn switch(&mut self, index: usize, ctx: &mut Context) {
+        if index == self.selected || index >= self.pages.len() {
+            return;
+        }
+        self.set_selected(index);
+        if let (Some(f), Some(title)) = (&self.on_change, self.titles.get(index)) {
+            let (f, title) = (Rc::clone(f), title.clone());
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, index, &title)),
+                None => f(self, index, &title),
+            }
+        }
+    }
+
+    /// * Area inside the block.
+    fn content(&self) -> Rect {
+        self.block
+            .as_ref()
+            .map_or(self.shape, |b| b.inner(self.shape))
+    }
+
+    /// Draw the tab bar on the first row of `content`.
+    fn draw_bar(&mut self, content: Rect, buf: &mut Buffer) {
+        self.title_columns.clear();
+        let end = content.x + content.w.max(0.0).floor();
+        let mut x = content.x;
+        for (i, title) in self.titles.iter().enumerate() {
+            if i > 0 {
+                let divider = truncate(&self.divider, (end - x).max(0.0) as usize);
+                buf.draw((x, content.y), &divider);
+                x += text_width(&divider) as f32;
+            }
+            let text = truncate(&format!(" {title} "), (end - x).max(0.0) as usize);
+            let width = text_width(&text) as f32;
+            buf.draw((x, content.y), &text);
+            if i == self.selected {
+                let area = Rect::new(x, content.y, width, 1.0);
+                buf.set_style(area, self.highlight_style);
+                if self.focused {
+                    buf.set_style(area, self.focus_style);
+                }
+            }
+            self.title_columns.push((x, width));
+            x += width;
+        }
+    }
+}
+
+impl Widget for Tabs {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        self.shape = rect;
+        buf.set_style(rect, self.style);
+        if let Some(block) = &self.block {
+            block.draw(rect, buf);
+        }
+
+        let content = self.content();
+        if content.is_empty() {
+            return;
+        }
+        self.draw_bar(content, buf);
+        let page = Rect::new(content.x, content.y + 1.0, content.w, content.h - 1.0);
+        if let Some(w) = self.pages.get_mut(self.selected) {
+            if !page.is_empty() {
+                w.render(page, buf);
+            }
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        let (width, space) = match &self.block {
+            Some(b) => (width - b.horizontal_space(), b.vertical_space()),
+            None => (width, 0.0),
+        };
+        let page = match self.pages.get(self.selected) {
+            Some(w) => w.preferred_height(width.max(0.0))?,
+            None => 0.0,
+        };
+        Some(1.0 + page + space)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let content = self.content();
+        match event {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if content.contains(*column, *row) && *row as f32 == content.y => {
+                let x = *column as f32;
+                let clicked = self
+                    .title_columns
+                    .iter()
+                    .position(|(start, width)| x >= *start && x < start + width);
+                if let Some(i) = clicked {
+                    self.switch(i, ctx);
+                }
+                return true;
+            }
+            _ => {}
+        }
+
+        if let Some(w) = self.pages.get_mut(self.selected) {
+            if w.handle_event(event, ctx) {
+                return true;
+            }
+        }
+
+        let Event::Key(key) = event else {
+            return false;
+        };
+        if !self.focused || key.kind == KeyEventKind::Release || self.pages.is_empty() {
+            return false;
+        }
+        let next = match key.code {
+            KeyCode::Left => self.selected.saturating_sub(1),
+            KeyCode::Right => (self.selected + 1).min(self.pages.len() - 1),
+            KeyCode::Home => 0,
+            KeyCode::End => self.pages.len() - 1,
+            _ => return false,
+        };
+        self.switch(next, ctx);
+        true
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        match self.pages.get(self.selected) {
+            Some(_) => &self.pages[self.selected..=self.selected],
+            None => &[],
+        }
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        match self.pages.get(self.selected) {
+            Some(_) => &mut self.pages[self.selected..=self.selected],
+            None => &mut [],
+        }
+    }
+
+    fn all_children(&self) -> Vec<&dyn Widget> {
+        self.pages.iter().map(|w| w.as_ref()).collect()
+    }
+
+    fn all_children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.pages.iter_mut().map(|w| w.as_mut()).collect()
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
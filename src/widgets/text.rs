@@ -0,0 +1,185 @@
+use crate::{Span, Style};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// * How text longer than its area is split into rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextWrap {
+    /// One row per line, cut with `…`.
+    #[default]
+    None,
+
+    /// Break anywhere.
+    Char,
+
+    /// Break between words, long words are broken anywhere.
+    Word,
+}
+
+/// Appended to text cut by `truncate`.
+pub const ELLIPSIS: &str = "…";
+
+/// * Number of columns `text` takes on the terminal.
+/// * Measured per grapheme cluster: accents take no extra column, CJK and emoji take two.
+///
+/// ```
+/// use simple_tui::text_width;
+///
+/// assert_eq!(text_width("abc"), 3);
+/// assert_eq!(text_width("héllo"), 5);
+/// assert_eq!(text_width("日本"), 4);
+/// ```
+pub fn text_width(text: &str) -> usize {
+    text.graphemes(true).map(grapheme_width).sum()
+}
+
+/// * Number of columns of a single grapheme cluster.
+pub fn grapheme_width(grapheme: &str) -> usize {
+    // Emoji sequences (flags, ZWJ) are one glyph, at most two columns.
+    grapheme.width().min(2)
+}
+
+/// * Longest start of `text` that fits in `max_width` columns.
+pub fn clip(text: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (i, g) in text.grapheme_indices(true) {
+        width += grapheme_width(g);
+        if width > max_width {
+            return &text[..i];
+        }
+    }
+    text
+}
+
+/// * Fit `text` in `max_width` columns, ending with `…` when it's cut.
+///
+/// ```
+/// use simple_tui::truncate;
+///
+/// assert_eq!(truncate("Hello", 5), "Hello");
+/// assert_eq!(truncate("Hello world", 8), "Hello w…");
+/// assert_eq!(truncate("日本語", 4), "日…");
+/// ```
+pub fn truncate(text: &str, max_width: usize) -> String {
+    if text_width(text) <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+    clip(text, max_width - 1).to_string() + ELLIPSIS
+}
+
+/// * Number of columns all `spans` take.
+pub fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|s| text_width(&s.text)).sum()
+}
+
+/// * Fit `spans` in `max_width` columns, ending with `…` when they're cut.
+pub fn truncate_spans(spans: &[Span], max_width: usize) -> Vec<Span> {
+    if spans_width(spans) <= max_width {
+        return spans.to_vec();
+    }
+    let mut left = max_width.saturating_sub(1);
+    let mut out = Vec::new();
+    for span in spans {
+        let text = clip(&span.text, left);
+        left -= text_width(text);
+        if text.len() < span.text.len() {
+            if max_width > 0 {
+                out.push(Span::styled(text.to_string() + ELLIPSIS, span.style));
+            }
+            break;
+        }
+        out.push(span.clone());
+    }
+    out
+}
+
+/// * Split `spans` into rows of at most `width` columns.
+/// * `\n` always starts a new row, `wrap` decides what happens to longer lines.
+///
+/// ```
+/// use simple_tui::*;
+///
+/// let rows = wrap_spans(&[Span::raw("the quick brown fox")], 10, TextWrap::Word);
+/// let rows: Vec<String> = rows
+///     .iter()
+///     .map(|row| row.iter().map(|s| s.text.as_str()).collect())
+///     .collect();
+///
+/// assert_eq!(rows, vec!["the quick", "brown fox"]);
+/// ```
+pub fn wrap_spans(spans: &[Span], width: usize, wrap: TextWrap) -> Vec<Vec<Span>> {
+    // split into lines of (grapheme, style).
+    let mut lines: Vec<Vec<(&str, Style)>> = vec![Vec::new()];
+    for span in spans {
+        for (i, part) in span.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Vec::new());
+            }
+            let line = lines.last_mut().unwrap();
+            line.extend(part.graphemes(true).map(|g| (g, span.style)));
+        }
+    }
+
+    let mut rows = Vec::new();
+    for line in lines {
+        if wrap == TextWrap::None {
+            rows.push(truncate_spans(&join(&line), width));
+            continue;
+        }
+        if width == 0 {
+            continue;
+        }
+
+        let mut row: Vec<(&str, Style)> = Vec::new();
+        let mut row_width = 0;
+        let mut continued = false;
+        for (g, style) in line {
+            let w = grapheme_width(g);
+            if row_width + w > width && !row.is_empty() {
+                // break after the last space of the row, if any.
+                let at = match wrap {
+                    TextWrap::Word => row.iter().rposition(|(g, _)| is_space(g)).map(|i| i + 1),
+                    _ => None,
+                };
+                let rest = match at {
+                    Some(at) => row.split_off(at),
+                    None => Vec::new(),
+                };
+                while row.last().is_some_and(|(g, _)| is_space(g)) && wrap == TextWrap::Word {
+                    row.pop();
+                }
+                rows.push(join(&row));
+                row = rest;
+                row_width = row.iter().map(|(g, _)| grapheme_width(g)).sum();
+                continued = true;
+            }
+            // spaces at a break are dropped, indentation is kept.
+            if wrap == TextWrap::Word && continued && row.is_empty() && is_space(g) {
+                continue;
+            }
+            row.push((g, style));
+            row_width += w;
+        }
+        rows.push(join(&row));
+    }
+    rows
+}
+
+fn is_space(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// * Merge graphemes with the same style back into spans.
+fn join(graphemes: &[(&str, Style)]) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    for (g, style) in graphemes {
+        match spans.last_mut() {
+            Some(last) if last.style == *style => last.text.push_str(g),
+            _ => spans.push(Span::styled(*g, *style)),
+        }
+    }
+    spans
+}
@@ -0,0 +1,420 @@
+use super::{Id, IntoId, Rect, Widget};
+use crate::{clip, grapheme_width, truncate, Block, Buffer, Context, Style};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::{any::Any, rc::Rc};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// * Callback triggered by `on_change` and `on_submit`, receives the input text.
+pub type InputCallback = Rc<dyn Fn(&mut dyn Widget, &str)>;
+
+/// * Message sent by `on_change_msg` and `on_submit_msg`, made from the input text.
+pub type InputMessage = Rc<dyn Fn(&str) -> Box<dyn Any>>;
+
+/// * What happened to the input after a keystroke.
+pub enum InputEvent {
+    /// Key used, the text didn't change (cursor moved, input full).
+    Unchanged,
+
+    /// Text was inserted or deleted.
+    Changed,
+
+    /// `Enter` was pressed.
+    Submitted,
+}
+
+/// * Single-line text input.
+/// * The cursor moves and deletes by grapheme clusters, so an accent or an emoji sequence
+///   is edited as one character.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::functions::*;
+///
+/// let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+/// let mut input = text_input().text("ae\u{301}👨‍👩‍👧");
+/// assert_eq!(input.cursor(), 3);
+///
+/// input.handle_key(&key(KeyCode::Left));
+/// input.handle_key(&key(KeyCode::Backspace));
+/// assert_eq!(input.text, "a👨‍👩‍👧");
+///
+/// input.handle_key(&key(KeyCode::Delete));
+/// assert_eq!(input.text, "a");
+/// assert_eq!(text_width(&input.text), 1);
+/// ```
+pub struct TextInput {
+    /// Widget id
+    id: Option<usize>,
+
+    /// Text typed so far.
+    pub text: String,
+
+    /// Text displayed while `text` is empty.
+    placeholder: String,
+
+    /// Maximum number of characters (grapheme clusters).
+    max_len: Option<usize>,
+
+    /// Cursor position, in grapheme clusters.
+    cursor: usize,
+
+    /// First visible grapheme cluster, when the text is wider than the widget.
+    offset: usize,
+
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
+
+    /// Keyboard focus.
+    focused: bool,
+
+    /// Widget style.
+    style: Style,
+
+    /// Border style.
+    border_style: Style,
+
+    /// Placeholder style, drawn over the widget style.
+    placeholder_style: Style,
+
+    /// Drawn over the other styles when focused.
+    focus_style: Style,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after every change.
+    pub on_change: Option<InputCallback>,
+
+    /// Callback after `Enter`.
+    pub on_submit: Option<InputCallback>,
+
+    /// Message sent after every change.
+    pub change_msg: Option<InputMessage>,
+
+    /// Message sent after `Enter`.
+    pub submit_msg: Option<InputMessage>,
+
+    /// Widget shape.
+    pub shape: Rect,
+}
+
+impl TextInput {
+    /// * Construct new empty text input.
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            text: String::new(),
+            placeholder: String::new(),
+            max_len: None,
+            cursor: 0,
+            offset: 0,
+            block: Some(Block::bordered()),
+            focused: false,
+            style: Style::default(),
+            border_style: Style::default(),
+            placeholder_style: Style::default().dim(),
+            focus_style: Style::default().bold(),
+            rid: None,
+            on_change: None,
+            on_submit: None,
+            change_msg: None,
+            submit_msg: None,
+            shape: Rect::default(),
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Set the initial text, the cursor goes to the end.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self.cursor = self.len();
+        self
+    }
+
+    /// Set text displayed while the input is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set maximum number of characters (grapheme clusters).
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// * Set the style of the whole widget, text included.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// * Set the style of the border, drawn over the widget and block styles.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// * Set the style of the placeholder, dim by default.
+    pub fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// * Set the style used while the input has the keyboard focus, bold by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    /// Check if Widget's wrap around.
+    pub fn is_wrap(&self) -> bool {
+        self.block.is_some()
+    }
+
+    /// Don't wrap around.
+    pub fn no_wrap(mut self) -> Self {
+        self.block = None;
+        self
+    }
+
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Cursor position, in grapheme clusters.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// * Replace the text, without calling `on_change`. The cursor goes to the end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.cursor = self.len();
+    }
+
+    /// * Storing the callback, triggered with the new text after every change.
+    pub fn on_change(mut self, f: impl Fn(&mut dyn Widget, &str) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// * Storing the callback, triggered with the text when `Enter` is pressed.
+    pub fn on_submit(mut self, f: impl Fn(&mut dyn Widget, &str) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(f));
+        self
+    }
+
+    /// * Send the message made by `f` from the new text to the `App` after every change. See `App`.
+    pub fn on_change_msg<M: 'static>(mut self, f: impl Fn(&str) -> M + 'static) -> Self {
+        self.change_msg = Some(Rc::new(move |text| Box::new(f(text))));
+        self
+    }
+
+    /// * Send the message made by `f` from the text to the `App` when `Enter` is pressed. See `App`.
+    pub fn on_submit_msg<M: 'static>(mut self, f: impl Fn(&str) -> M + 'static) -> Self {
+        self.submit_msg = Some(Rc::new(move |text| Box::new(f(text))));
+        self
+    }
+
+    /// Number of grapheme clusters in the text.
+    fn len(&self) -> usize {
+        self.text.graphemes(true).count()
+    }
+
+    /// Byte index of the `idx`th grapheme cluster.
+    fn byte_index(&self, idx: usize) -> usize {
+        self.text
+            .grapheme_indices(true)
+            .nth(idx)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    /// * Edit the text with a keystroke.
+    /// * Return `None` if the keystroke wasn't used.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> Option<InputEvent> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let mut event = InputEvent::Unchanged;
+        let len = self.len();
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if self.max_len.is_some_and(|max| len >= max) {
+                    return Some(InputEvent::Unchanged);
+                }
+                let i = self.byte_index(self.cursor);
+                self.text.insert(i, c);
+                // a combining mark joins the cluster before it.
+                self.cursor = self.text[..i + c.len_utf8()].graphemes(true).count();
+                event = InputEvent::Changed;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let range = self.byte_index(self.cursor)..self.byte_index(self.cursor + 1);
+                self.text.replace_range(range, "");
+                event = InputEvent::Changed;
+            }
+            KeyCode::Delete if self.cursor < len => {
+                let range = self.byte_index(self.cursor)..self.byte_index(self.cursor + 1);
+                self.text.replace_range(range, "");
+                event = InputEvent::Changed;
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(len),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = len,
+            KeyCode::Enter => event = InputEvent::Submitted,
+            KeyCode::Backspace | KeyCode::Delete => {}
+            _ => return None,
+        }
+        Some(event)
+    }
+
+    /// Render the text, scrolled so the cursor stays visible.
+    fn render_text(&mut self, x: f32, y: f32, width: usize, buf: &mut Buffer) {
+        if width == 0 {
+            return;
+        }
+        let graphemes: Vec<&str> = self.text.graphemes(true).collect();
+        // `text` is public, it may have changed under the cursor.
+        self.cursor = self.cursor.min(graphemes.len());
+        let columns = |range: &[&str]| -> usize { range.iter().map(|g| grapheme_width(g)).sum() };
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        }
+        // keep a column for the cursor itself.
+        while self.offset < self.cursor && columns(&graphemes[self.offset..self.cursor]) >= width {
+            self.offset += 1;
+        }
+
+        if self.text.is_empty() {
+            let placeholder = truncate(&self.placeholder, width);
+            buf.draw_styled((x, y), &placeholder, self.placeholder_style);
+        } else {
+            let visible = graphemes[self.offset..].concat();
+            buf.draw((x, y), clip(&visible, width));
+        }
+
+        if self.focused {
+            let column = columns(&graphemes[self.offset..self.cursor]);
+            buf.set_cursor(x + column as f32, y);
+        }
+    }
+}
+
+impl Widget for TextInput {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        // required.
+        let height = self
+            .block
+            .as_ref()
+            .map_or(3.0, |b| b.vertical_space() + 1.0);
+        self.shape = Rect::new(rect.x, rect.y, rect.w, rect.h.min(height));
+        buf.set_style(self.shape, self.style);
+        if self.focused {
+            buf.set_style(self.shape, self.focus_style);
+        }
+
+        let Some(block) = &self.block else {
+            // middle row of the shape.
+            let y = rect.y + ((self.shape.h - 1.0) * 0.5).floor();
+            self.render_text(rect.x, y, rect.w.max(0.0) as usize, buf);
+            return;
+        };
+
+        // wrap around
+        let border_style = block.border_style.patch(self.border_style);
+        block.draw_with(self.shape, buf, block.border_type, border_style);
+        let content = block.inner(self.shape);
+        if !content.is_empty() {
+            self.render_text(content.x, content.y, content.w as usize, buf);
+        }
+    }
+
+    fn shape(&self) -> &Rect {
+        &self.shape
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.id
+    }
+
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        Some(
+            self.block
+                .as_ref()
+                .map_or(3.0, |b| b.vertical_space() + 1.0),
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let Event::Key(key) = event else {
+            return false;
+        };
+        if !self.focused {
+            return false;
+        }
+        let Some(input_event) = self.handle_key(key) else {
+            return false;
+        };
+
+        let (callback, message) = match input_event {
+            InputEvent::Changed => (&self.on_change, &self.change_msg),
+            InputEvent::Submitted => (&self.on_submit, &self.submit_msg),
+            InputEvent::Unchanged => (&None, &None),
+        };
+        if let Some(msg) = message {
+            ctx.send_any(msg(&self.text));
+        }
+        if let Some(f) = callback {
+            let (f, text) = (Rc::clone(f), self.text.clone());
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, &text)),
+                None => f(self, &text),
+            }
+        }
+        true
+    }
+
+    fn restore(&mut self, old: &dyn Widget) {
+        if let Some(old) = old.downcast_ref::<Self>() {
+            self.cursor = old.cursor;
+            self.offset = old.offset;
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,173 @@
+use super::{CheckCallback, Checkbox, Id, IntoId, Rect, Widget};
+use crate::{Buffer, Color, Context, Style};
+use crossterm::event::Event;
+use std::rc::Rc;
+
+/// * On/off switch followed by its label.
+/// * A click or `Space` (when focused) flips it, like a `Checkbox`.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+/// let mut wifi = toggle("Wi-Fi").on(true);
+/// terminal.draw(|rect, buf| wifi.render(rect, buf)).unwrap();
+///
+/// assert_eq!(terminal.backend().lines(), vec!["[ ON] Wi-Fi "]);
+/// ```
+///
+/// Without a related id, `on_change` gets the Toggle itself:
+///
+/// ```
+/// use simple_tui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use simple_tui::functions::*;
+///
+/// // a switch that can't be turned on.
+/// let mut locked = toggle("Locked").on_change(|w: &mut dyn Widget, _: bool| {
+///     w.downcast_mut::<Toggle>().unwrap().set_on(false)
+/// });
+/// locked.set_focus(true);
+/// locked.dispatch(&Event::Key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE))).unwrap();
+///
+/// assert!(!locked.is_on());
+/// ```
+pub struct Toggle {
+    /// Switch, drawn as a checkbox with other symbols.
+    inner: Checkbox,
+
+    /// Related id.
+    pub rid: Option<Id>,
+
+    /// Callback after the state changed.
+    pub on_change: Option<CheckCallback>,
+}
+
+impl Toggle {
+    /// * Construct new toggle, off.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            inner: Checkbox::new(text)
+                .symbols("[ ON]", "[OFF]")
+                .checked_style(Style::default().fg(Color::Green)),
+            rid: None,
+            on_change: None,
+        }
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.inner = self.inner.set_id(id);
+        self
+    }
+
+    /// * Set related id for any Widget in the tree. \
+    pub fn set_rid(mut self, rid: impl IntoId) -> Self {
+        self.rid = Some(rid.into_id());
+        self
+    }
+
+    /// Set the initial state.
+    pub fn on(mut self, on: bool) -> Self {
+        self.inner = self.inner.checked(on);
+        self
+    }
+
+    /// * Set the switch drawn when on and off, `[ ON]` and `[OFF]` by default.
+    pub fn symbols(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.inner = self.inner.symbols(on, off);
+        self
+    }
+
+    /// * Set the style of the switch when on, green by default.
+    pub fn on_style(mut self, style: Style) -> Self {
+        self.inner = self.inner.checked_style(style);
+        self
+    }
+
+    /// * Set the style of the whole widget.
+    pub fn style(mut self, style: Style) -> Self {
+        self.inner = self.inner.style(style);
+        self
+    }
+
+    /// * Set the style used while the widget has the keyboard focus, reversed by default.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.inner = self.inner.focus_style(style);
+        self
+    }
+
+    /// * Storing the callback, triggered with the new state.
+    pub fn on_change(mut self, f: impl Fn(&mut dyn Widget, bool) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// * Send the message made by `f` from the new state to the `App`. See `App`.
+    pub fn on_change_msg<M: 'static>(mut self, f: impl Fn(bool) -> M + 'static) -> Self {
+        self.inner = self.inner.on_change_msg(f);
+        self
+    }
+
+    /// Switch state.
+    pub fn is_on(&self) -> bool {
+        self.inner.is_checked()
+    }
+
+    /// Set the state, without calling `on_change`.
+    pub fn set_on(&mut self, on: bool) {
+        self.inner.set_checked(on);
+    }
+
+    /// Text after the switch.
+    pub fn text(&self) -> &str {
+        &self.inner.text
+    }
+}
+
+impl Widget for Toggle {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        self.inner.render(rect, buf);
+    }
+
+    fn shape(&self) -> &Rect {
+        self.inner.shape()
+    }
+
+    fn id(&self) -> Option<usize> {
+        self.inner.id()
+    }
+
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        self.inner.preferred_height(width)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        let on = self.is_on();
+        if !self.inner.handle_event(event, ctx) {
+            return false;
+        }
+        if let Some(f) = self.on_change.as_ref().filter(|_| self.is_on() != on) {
+            let (f, on) = (Rc::clone(f), self.is_on());
+            match self.rid.clone() {
+                Some(rid) => ctx.trigger(rid, move |w| f(w, on)),
+                None => f(self, on),
+            }
+        }
+        true
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_focused(&self) -> bool {
+        self.inner.is_focused()
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.inner.set_focus(focused);
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Rect {
     pub x: f32,
     pub y: f32,
@@ -9,11 +9,57 @@ impl Rect {
     pub fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
         Self { x, y, w, h }
     }
+
+    /// * Check if the Rect has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.w < 1.0 || self.h < 1.0
+    }
+
+    /// * Check if the cell (column, row) is inside the Rect.
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        let (column, row) = (column as f32, row as f32);
+        self.x <= column && column < self.x + self.w && self.y <= row && row < self.y + self.h
+    }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Alignment {
+    #[default]
     Left,
     Center,
     Right,
     // Custom { width: u32, hight: u32 },
 }
+
+impl Alignment {
+    /// * Columns to skip before text of `width` columns in an area of `available` columns.
+    pub fn offset(&self, available: usize, width: usize) -> usize {
+        let space = available.saturating_sub(width);
+        match self {
+            Alignment::Left => 0,
+            Alignment::Center => space / 2,
+            Alignment::Right => space,
+        }
+    }
+}
+
+/// * Vertical alignment, for Widgets taller than their content.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VAlignment {
+    Top,
+    #[default]
+    Middle,
+    Bottom,
+}
+
+impl VAlignment {
+    /// * Rows to skip before content of `height` rows in an area of `available` rows.
+    pub fn offset(&self, available: usize, height: usize) -> usize {
+        let space = available.saturating_sub(height);
+        match self {
+            VAlignment::Top => 0,
+            VAlignment::Middle => space / 2,
+            VAlignment::Bottom => space,
+        }
+    }
+}
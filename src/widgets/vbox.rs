@@ -1,224 +1,166 @@
-use super::{Rect, Widget};
-use crate::{inner_hbox_render, inner_hbox_trigger, AsWidget, Console, EventHandler};
-use crossterm::{
-    event::{
-        read, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
-    },
-    terminal::{disable_raw_mode, enable_raw_mode},
-};
-
+use super::{render_child, IntoId, Rect, Widget};
+use crate::{split, Block, Buffer, Constraint, Context, Direction};
+use crossterm::event::Event;
+
+/// * Widgets stacked from top to bottom, sized by their `constraints`.
+/// * Children left without room get an empty Rect: they aren't drawn and can't be clicked.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::crossterm::event::*;
+/// use simple_tui::functions::*;
+///
+/// let mut root = vbox(widgets![
+///     lable("").set_id("status"),
+///     button("Delete")
+///         .set_rid("status")
+///         .on_click(|w: &mut dyn Widget| w.downcast_mut::<Lable>().unwrap().text = "deleted".into())
+/// ]);
+/// Terminal::new(TestBackend::new(10, 4)).unwrap().draw(|rect, buf| root.render(rect, buf)).unwrap();
+/// // smaller terminal, no room left for the button.
+/// Terminal::new(TestBackend::new(10, 1)).unwrap().draw(|rect, buf| root.render(rect, buf)).unwrap();
+///
+/// root.dispatch(&Event::Mouse(MouseEvent {
+///     kind: MouseEventKind::Down(MouseButton::Left),
+///     column: 2,
+///     row: 2,
+///     modifiers: KeyModifiers::NONE,
+/// }))
+/// .unwrap();
+///
+/// assert!(root.widgets[1].shape().is_empty());
+/// assert_eq!(root.find_by_id("status").unwrap().downcast_ref::<Lable>().unwrap().text, "");
+/// ```
 pub struct Vbox {
     /// Widget id
     id: Option<usize>,
 
     /// Widgets
-    pub widgets: Vec<Option<Widget>>,
+    pub widgets: Vec<Box<dyn Widget>>,
 
-    /// Console controler
-    console: Console,
+    /// Children heights, their preferred height or `Constraint::Length(3)` when missing.
+    constraints: Vec<Constraint>,
 
-    /// Wrap around.
-    wrap: bool,
+    /// Border, title and padding, wrap around.
+    block: Option<Block>,
 
     /// Widget shape.
     pub shape: Rect,
 }
 
 impl Vbox {
-    pub fn new(widgets: Vec<Option<Widget>>) -> Self {
+    pub fn new(widgets: Vec<Box<dyn Widget>>) -> Self {
         Self {
             id: None,
             widgets,
-            console: Console::new(),
+            constraints: Vec::new(),
             shape: Rect::default(),
-            wrap: false,
+            block: None,
         }
     }
 
     /// Check if Widget's wrap around.
     pub fn is_wrap(&self) -> bool {
-        self.wrap
+        self.block.is_some()
     }
 
     /// Wrap around
-    pub fn wrap() {}
+    pub fn wrap(mut self) -> Self {
+        self.block = Some(Block::bordered());
+        self
+    }
 
-    /// Widget id
-    pub fn id(&self) -> Option<usize> {
-        self.id
+    /// * Wrap around with a custom border, title and padding.
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
     }
 
-    /// Set Widget id
-    pub fn set_id(mut self, id: usize) -> Self {
-        self.id = Some(id);
+    /// * Set children heights, in the same order as the widgets.
+    /// * Children without a constraint get their preferred height, or `Constraint::Length(3)`.
+    pub fn constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.constraints = constraints;
         self
     }
 
-    pub fn render(&mut self, rect: Rect) {
-        let n = self.widgets.len();
-        let wh = rect.h / n as f32;
-        for idx in 0..n {
-            let mut padding = 1.0 as f32;
-            if let Widget::Vbox(_) | Widget::Hbox(_) = self.widgets[idx].as_ref().unwrap() {
-                padding = 0.0;
-            }
-            self.widgets[idx].as_mut().unwrap().render(Rect::new(
-                rect.x,
-                (rect.y + 3.0 * idx as f32 + padding).floor(),
-                rect.w,
-                wh,
-            ))
-        }
+    /// * Area of each child inside `rect`, inside the block if wrapped.
+    pub fn layout(&self, rect: Rect) -> Vec<Rect> {
+        let rect = self.block.as_ref().map_or(rect, |b| b.inner(rect));
+        split(rect, Direction::Vertical, &self.resolved(rect.w))
     }
-}
 
-impl AsWidget for Vbox {
-    fn as_widget(self) -> Option<Widget> {
-        Some(Widget::Vbox(self))
+    /// * Constraint of each child when the Vbox is `width` columns wide.
+    fn resolved(&self, width: f32) -> Vec<Constraint> {
+        self.widgets
+            .iter()
+            .enumerate()
+            .map(|(i, w)| match self.constraints.get(i) {
+                Some(c) => *c,
+                None => match w.preferred_height(width) {
+                    Some(h) => Constraint::Length(h.ceil().max(0.0) as u16),
+                    None => Constraint::Length(3),
+                },
+            })
+            .collect()
+    }
+
+    /// Set Widget id
+    pub fn set_id(mut self, id: impl IntoId) -> Self {
+        self.id = Some(id.into_id().value());
+        self
     }
 }
 
-impl EventHandler for Vbox {
-    fn start(&mut self) {
-        enable_raw_mode().unwrap();
-        self.console.clear();
-        loop {
-            let (mx, my) = self.console.max_xy();
-            let rect = Rect::new(0.0, 0.0, mx, my);
-            self.render(rect);
-            self.handle_event();
+impl Widget for Vbox {
+    /// Render the widget into the buffer.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+        if let Some(block) = &self.block {
+            block.draw(rect, buf);
+        }
+        let rects = self.layout(rect);
+        for (w, r) in self.widgets.iter_mut().zip(rects) {
+            render_child(w.as_mut(), r, buf);
         }
+
+        // required.
+        self.shape = rect;
     }
 
-    fn handle_event(&mut self) {
-        let event = read().unwrap();
-        match event {
-            Event::Resize(_, _) => self.console.clear(),
-            Event::Mouse(MouseEvent {
-                kind, column, row, ..
-            }) => {
-                if kind == MouseEventKind::Down(MouseButton::Left) {
-                    // loop over the widgets
-                    // check if the Cursor after the click in Widget area
-                    // then trigger the callback
-                    for wi in 0..self.widgets.len() {
-                        let shape = self.widgets[wi].as_ref().unwrap().shape();
-                        if shape.x as u16 <= column
-                            && shape.w as u16 >= column
-                            && shape.y as u16 == row
-                        {
-                            if let Widget::Button(_) = self.widgets[wi].as_ref().unwrap() {
-                                let clicked_widget = self.widgets[wi].take();
-                                self.widgets[wi] = self.trigger_callback(clicked_widget);
-
-                                self.console.clear();
-                            }
-                        }
-
-                        // Same process but for inner Widgets.
-                        if let Widget::Hbox(hb) = self.widgets[wi].as_mut().unwrap() {
-                            inner_hbox_render(hb, column, row);
-                        }
-
-                        // Same process but for inner Widgets.
-                        if let Widget::Vbox(vb) = self.widgets[wi].as_mut().unwrap() {
-                            inner_vbox_render(vb, column, row);
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-        if event == Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)) {
-            disable_raw_mode().unwrap();
-            self.console.clear();
-            std::process::exit(0);
-        };
+    fn shape(&self) -> &Rect {
+        &self.shape
     }
 
-    fn trigger_callback(&mut self, clicked_widget: Option<Widget>) -> Option<Widget> {
-        if let Widget::Button(btn) = clicked_widget.as_ref().unwrap() {
-            match btn.callback {
-                Some(ref f) => {
-                    let rid = btn
-                        .rid
-                        .expect("Please make sure you set the rid through .set_rid(rid: usize)");
-
-                    for wi in 0..self.widgets.len() {
-                        match self.widgets[wi].as_mut().unwrap() {
-                            // Inner Widgets
-                            Widget::Vbox(vb) => {
-                                if inner_vbox_trigger(vb, f, rid) {
-                                    return clicked_widget;
-                                }
-                            }
-                            // Inner Widgets
-                            Widget::Hbox(hb) => {
-                                if inner_hbox_trigger(hb, f, rid) {
-                                    return clicked_widget;
-                                }
-                            }
-                            // Outer Widgets
-                            w @ _ => {
-                                if let Some(id) = w.id() {
-                                    if rid == id {
-                                        self.widgets[wi] = Some(
-                                            f(self.widgets[wi].take().as_mut().unwrap()).take(),
-                                        );
-                                        return clicked_widget;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                _ => {}
-            }
-        }
-        clicked_widget
+    fn id(&self) -> Option<usize> {
+        self.id
     }
-}
 
-pub fn inner_vbox_render(vbox: &mut Vbox, column: u16, row: u16) {
-    for wi in 0..vbox.widgets.len() {
-        let shape = vbox.widgets[wi].as_ref().unwrap().shape();
-
-        if shape.x as u16 <= column
-            && (shape.w - 1.0) as u16 >= column
-            && (shape.y - 0.0) as u16 == row
-        {
-            if let Widget::Button(_) = vbox.widgets[wi].as_ref().unwrap() {
-                let clicked_widget = vbox.widgets[wi].take();
-                vbox.widgets[wi] = vbox.trigger_callback(clicked_widget);
-                crate::Console::new().flush();
-            }
-        }
+    fn preferred_height(&self, width: f32) -> Option<f32> {
+        // only known when every child has a fixed height.
+        let (width, space) = match &self.block {
+            Some(b) => (width - b.horizontal_space(), b.vertical_space()),
+            None => (width, 0.0),
+        };
+        let height: Option<f32> = self
+            .resolved(width.max(0.0))
+            .iter()
+            .map(|c| match c {
+                Constraint::Length(n) => Some(*n as f32),
+                _ => None,
+            })
+            .sum();
+        height.map(|h| h + space)
+    }
 
-        if let Widget::Hbox(hb) = vbox.widgets[wi].as_mut().unwrap() {
-            inner_hbox_render(hb, column, row);
-        }
+    fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> bool {
+        self.widgets.iter_mut().any(|w| w.handle_event(event, ctx))
+    }
 
-        if let Widget::Vbox(vb) = vbox.widgets[wi].as_mut().unwrap() {
-            inner_vbox_render(vb, column, row);
-        }
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &self.widgets
     }
-}
 
-pub fn inner_vbox_trigger(
-    vbox: &mut Vbox,
-    f: impl Fn(&mut Widget) -> &mut Widget,
-    rid: usize,
-) -> bool {
-    for wi in 0..vbox.widgets.len() {
-        if let Some(w) = vbox.widgets[wi].as_ref() {
-            if let Some(id) = w.id() {
-                if rid == id {
-                    vbox.widgets[wi] = Some(f(vbox.widgets[wi].take().as_mut().unwrap()).take());
-                    return true;
-                }
-            }
-        }
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut self.widgets
     }
-    false
 }
@@ -1,57 +1,279 @@
-use crate::widgets::*;
-
-pub enum Widget {
-    Vbox(Vbox),
-    Hbox(Hbox),
-    Button(Button),
-    Lable(Lable),
-    None,
-}
-
-impl Widget {
-    /// * Render the Widget on the terminal.
-    pub fn render(&mut self, rect: Rect) {
-        match self {
-            Widget::Button(b) => b.render(rect),
-            Widget::Lable(l) => l.render(rect),
-            Widget::Vbox(vb) => vb.render(rect),
-            Widget::Hbox(hb) => hb.render(rect),
-            _ => {
-                panic!("Can not render Widget. Because Widget doesn't have `.render(&mut self, rect: Rect)` method")
-            }
+use crate::{Buffer, Context, Rect};
+use crossterm::event::Event;
+use std::{any::Any, fmt};
+
+/// * Upcast to `Any`, implemented for every `'static` type.
+/// * Lets `dyn Widget` be downcast back to its concrete type.
+pub trait AsAny: Any {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// * Every Widget, built-in or custom, implements this trait.
+/// * Containers hold their children as `Box<dyn Widget>`.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// struct Dot {
+///     shape: Rect,
+/// }
+///
+/// impl Widget for Dot {
+///     fn render(&mut self, rect: Rect, buf: &mut Buffer) {
+///         buf.draw((rect.x, rect.y), "•");
+///         self.shape = rect;
+///     }
+///
+///     fn shape(&self) -> &Rect {
+///         &self.shape
+///     }
+/// }
+///
+/// let root = vbox(widgets![lable("Dot:"), Dot { shape: Rect::default() }]);
+/// ```
+pub trait Widget: AsAny {
+    /// * Render the Widget into the buffer.
+    /// * Save `rect` so `shape` can return it.
+    fn render(&mut self, rect: Rect, buf: &mut Buffer);
+
+    /// * Shape of the Widget, the area it was last rendered in.
+    fn shape(&self) -> &Rect;
+
+    /// Widget id
+    fn id(&self) -> Option<usize> {
+        None
+    }
+
+    /// * Rows the Widget needs when it's `width` columns wide.
+    /// * `Vbox` uses it for children without a constraint, `None` lets the container decide.
+    fn preferred_height(&self, _width: f32) -> Option<f32> {
+        None
+    }
+
+    /// * Handle an input event, callbacks are pushed into `ctx`.
+    /// * Return true if the event was used, so nobody else gets it.
+    fn handle_event(&mut self, _event: &Event, _ctx: &mut Context) -> bool {
+        false
+    }
+
+    /// * Called on every tick of the event loop, see `Runner::tick_rate`.
+    /// * Animated Widgets move on here, callbacks are pushed into `ctx`.
+    fn on_tick(&mut self, _ctx: &mut Context) {}
+
+    /// * Check if the Widget can receive keyboard focus.
+    fn focusable(&self) -> bool {
+        false
+    }
+
+    /// * Check if the Widget has keyboard focus.
+    fn is_focused(&self) -> bool {
+        false
+    }
+
+    /// * Give or take keyboard focus, ignored by Widgets that aren't focusable.
+    fn set_focus(&mut self, _focused: bool) {}
+
+    /// * Inner Widgets, for containers.
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &[]
+    }
+
+    /// * Mutable inner Widgets, for containers.
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut []
+    }
+
+    /// * Take the local state of `old` (cursor, scroll offset), the Widget at the same place
+    ///   when an `App` builds its view again; `old` is always of the same type.
+    /// * State the view sets (text, selection, checked) isn't taken.
+    fn restore(&mut self, _old: &dyn Widget) {}
+
+    /// * Every inner Widget, hidden ones included (other tabs, the base under a layer).
+    /// * Searched by `find_by_id`, so callbacks reach Widgets that aren't shown;
+    ///   focus, rendering and events only use `children`.
+    fn all_children(&self) -> Vec<&dyn Widget> {
+        self.children().iter().map(|w| w.as_ref()).collect()
+    }
+
+    /// * Mutable inner Widgets, hidden ones included.
+    fn all_children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.children_mut().iter_mut().map(|w| w.as_mut()).collect()
+    }
+}
+
+impl dyn Widget {
+    /// * Concrete Widget, `None` if it isn't a `T`.
+    pub fn downcast_ref<T: Widget>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// * Mutable concrete Widget, `None` if it isn't a `T`.
+    pub fn downcast_mut<T: Widget>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
+}
+
+/// * Widget id, a number or a name.
+/// * Names are hashed into the number `Widget::id` returns, so `set_id("total")` and
+///   `find_by_id("total")` meet; the name is kept to be shown in errors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Id {
+    /// Number compared with `Widget::id`.
+    value: usize,
+
+    /// Name it was made from.
+    name: Option<String>,
+}
+
+impl Id {
+    /// Number compared with `Widget::id`.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Name it was made from, `None` for a number.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name:?}"),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// * Anything usable as a Widget id: `usize`, `&str`, `String` or `Id`.
+pub trait IntoId {
+    fn into_id(self) -> Id;
+}
+
+impl IntoId for Id {
+    fn into_id(self) -> Id {
+        self
+    }
+}
+
+impl IntoId for usize {
+    fn into_id(self) -> Id {
+        Id {
+            value: self,
+            name: None,
+        }
+    }
+}
+
+impl IntoId for &str {
+    /// * FNV-1a, stable across runs and platforms of the same width.
+    fn into_id(self) -> Id {
+        let hash = self.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        Id {
+            value: hash as usize,
+            name: Some(self.to_string()),
         }
     }
+}
+
+impl IntoId for &String {
+    fn into_id(self) -> Id {
+        self.as_str().into_id()
+    }
+}
+
+impl IntoId for String {
+    fn into_id(self) -> Id {
+        self.as_str().into_id()
+    }
+}
 
-    /// * Shape of the Widget.
-    pub fn shape(&self) -> &Rect {
-        match self {
-            Widget::Button(b) => &b.shape,
-            Widget::Lable(l) => &l.shape,
-            Widget::Vbox(vb) => &vb.shape,
-            Widget::Hbox(hb) => &hb.shape,
-            _ => {
-                panic!(
-                    "Can not find shape for this Widget. Because Widget doesn't have shape field."
-                )
-            }
+/// * Widget with id `id` under `widget` (included), searched depth first.
+/// * Hidden Widgets are searched too: pages of other tabs, the base under an open layer.
+///
+/// Example:
+///
+/// ```
+/// use simple_tui::functions::*;
+///
+/// let mut root = overlay(tabs().tab("One", lable("")).tab("Two", lable("").set_id("hidden")));
+/// root.open(dialog("Sure?", ""));
+///
+/// assert!(find_by_id(&root, "hidden").is_some());
+///
+/// let mut ctx = Context::new();
+/// ctx.trigger("hidden", |w: &mut dyn Widget| {
+///     w.downcast_mut::<Lable>().unwrap().text = "found".into()
+/// });
+/// ctx.apply(&mut root).unwrap();
+///
+/// assert_eq!(root.get::<Lable>("hidden").unwrap().text, "found");
+/// ```
+pub fn find_by_id(widget: &dyn Widget, id: impl IntoId) -> Option<&dyn Widget> {
+    fn find(widget: &dyn Widget, id: usize) -> Option<&dyn Widget> {
+        if widget.id() == Some(id) {
+            return Some(widget);
         }
+        widget
+            .all_children()
+            .into_iter()
+            .find_map(|child| find(child, id))
     }
+    find(widget, id.into_id().value())
+}
 
-    pub fn id(&self) -> Option<usize> {
-        match self {
-            Widget::Vbox(vb) => vb.id(),
-            Widget::Hbox(rb) => rb.id(),
-            Widget::Button(b) => b.id(),
-            Widget::Lable(l) => l.id(),
-            _ => {
-                panic!("Can not find Widget id.")
-            }
+/// * Mutable Widget with id `id` under `widget` (included), searched depth first.
+pub fn find_by_id_mut(widget: &mut dyn Widget, id: impl IntoId) -> Option<&mut dyn Widget> {
+    fn find(widget: &mut dyn Widget, id: usize) -> Option<&mut dyn Widget> {
+        if widget.id() == Some(id) {
+            return Some(widget);
         }
+        widget
+            .all_children_mut()
+            .into_iter()
+            .find_map(|child| find(child, id))
+    }
+    find(widget, id.into_id().value())
+}
+
+/// * Visit `widget` and all its inner Widgets, depth first.
+pub fn walk(widget: &dyn Widget, f: &mut dyn FnMut(&dyn Widget)) {
+    f(widget);
+    for child in widget.children() {
+        walk(child.as_ref(), f);
     }
+}
+
+/// * Visit `widget` and all its inner Widgets mutably, depth first.
+pub fn walk_mut(widget: &mut dyn Widget, f: &mut dyn FnMut(&mut dyn Widget)) {
+    f(widget);
+    for child in widget.children_mut() {
+        walk_mut(child.as_mut(), f);
+    }
+}
 
-    /// * replace `Widget::Variant(T)` => `Widget::None`
-    /// * return previous `Widget::Variant(T)`
-    pub fn take(&mut self) -> Self {
-        std::mem::replace(self, Self::None)
+/// * Render the child `widget` of a container into `rect`.
+/// * When `rect` is empty nothing is drawn, but the child still takes it as its shape,
+///   so it can't be clicked where it was last drawn.
+pub(super) fn render_child(widget: &mut dyn Widget, rect: Rect, buf: &mut Buffer) {
+    match rect.is_empty() {
+        true => widget.render(rect, &mut Buffer::empty(0, 0)),
+        false => widget.render(rect, buf),
     }
 }